@@ -6,7 +6,74 @@
 @group(1) @binding(2) var material_color_texture: texture_2d<f32>;
 @group(1) @binding(3) var material_color_sampler: sampler;
 @group(1) @binding(4) var<uniform> border_color: vec4<f32>;
+@group(1) @binding(5) var<uniform> shape: vec4<f32>;
 
+const TAU: f32 = 6.28318530718;
+
+
+// darkens parts of the fill in the pattern selected by slider.z
+// so bars can be told apart without relying on color
+fn pattern_shade(position: vec2<f32>) -> f32 {
+    let dark = 0.55;
+    if slider.z > 3.5 {
+        // waves
+        if fract(position.y / 8.0) < 0.5 {
+            return dark;
+        }
+    } else if slider.z > 2.5 {
+        // checker
+        let cell = floor(position / 10.0);
+        if (i32(cell.x + cell.y) & 1) == 0 {
+            return dark;
+        }
+    } else if slider.z > 1.5 {
+        // dots
+        if length(fract(position / 12.0) - 0.5) < 0.25 {
+            return dark;
+        }
+    } else if slider.z > 0.5 {
+        // stripes
+        if fract((position.x + position.y) / 16.0) < 0.5 {
+            return dark;
+        }
+    }
+    return 1.0;
+}
+
+// how far along the fill uv is, from 0 where the fill mode in slider.y starts to 1 where it ends
+fn fill_position(uv: vec2<f32>) -> f32 {
+    if slider.y > 2.5 {
+        // clock wipe, sweeps from the angle in shape.x in the direction in shape.y
+        let p = uv - 0.5;
+        // angle clockwise from the top, as uv.y grows downwards
+        let angle = atan2(p.x, -p.y);
+        return fract((angle - shape.x) * shape.y / TAU);
+    } else if slider.y > 1.5 {
+        // radial, grows from the center until the corners are covered
+        return length(uv - 0.5) / 0.7072;
+    } else if slider.y > 0.5 {
+        // vertical, grows from the bottom
+        return 1.0 - uv.y;
+    }
+    // horizontal, grows from the left
+    return uv.x;
+}
+
+// whether uv is inside the part filled by the progress in slider.x
+fn is_filled(uv: vec2<f32>) -> bool {
+    let progress = slider.x;
+    let position = fill_position(uv);
+    let segments = shape.z;
+    if segments > 1.5 {
+        // segmented, each pip is filled once the progress covers all of it
+        let scaled = position * segments;
+        if fract(scaled) > 1.0 - shape.w {
+            return false;
+        }
+        return (floor(scaled) + 1.0) / segments <= progress + 0.0001;
+    }
+    return position < progress;
+}
 
 @fragment
 fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
@@ -48,11 +115,11 @@ fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
         }
     }
 
-    // sample the texture at this position if it's to the left of the slider value
+    // sample the texture at this position if it's inside the filled part
     // otherwise return a fully transparent color
-    if in.uv.x < slider.x && 1.0 - in.uv.y < slider.y {
+    if is_filled(in.uv) {
         let output_color = textureSample(material_color_texture, material_color_sampler, in.uv) * color;
-        return output_color;
+        return vec4(output_color.rgb * pattern_shade(in.uv * in.size), output_color.a);
     } else {
         return vec4(0.0);
     }
@@ -0,0 +1,14 @@
+//! Options that make the game more comfortable to play, changed in the settings menu.
+
+use bevy::prelude::*;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<ReducedMotion>();
+}
+
+/// Cuts down on movement that isn't needed to play: [`crate::float::Floats`] stay still,
+/// camera shake is capped and screen transitions fade instead of cutting.
+/// Fever no longer speeds anything up, as nothing drifts.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub struct ReducedMotion(pub bool);
@@ -0,0 +1,264 @@
+//! Plain text manifests listing the assets of a game, so assets can be added or swapped
+//! without touching Rust.
+//!
+//! A manifest has one asset per line, `#` starts a comment:
+//!
+//! ```text
+//! # kind  name      path                          options
+//! image   popups    games/popup/popup_large1.png  nearest
+//! image   popups    games/popup/popup_large2.png  nearest
+//! image   cat       games/cat/cat1-sheet.png      nearest atlas=128x60:2x1
+//! sound   bgm       audio/music/loop2.ogg
+//! ```
+//!
+//! Paths containing spaces can be wrapped in double quotes. Lines sharing a name form a group, e.g. all footstep sounds. `nearest` loads an image with
+//! a nearest neighbour sampler and `atlas=<tile width>x<tile height>:<columns>x<rows>` declares
+//! a [`TextureAtlasLayout`] for the image under the same name.
+//!
+//! Sounds and music played by more than one game live under `audio/` rather than in the
+//! folder of one of the games, so each manifest points there.
+//!
+//! A resource implementing [`FromManifest`] is loaded straight from its manifest file.
+
+use std::{error::Error, fmt, marker::PhantomData};
+
+use bevy::{
+    asset::{AssetLoader, AsyncReadExt, LoadContext, io::Reader},
+    image::{ImageLoaderSettings, ImageSampler},
+    platform::collections::HashMap,
+    prelude::*,
+};
+
+/// Builds a typed asset resource from a loaded [`AssetManifest`].
+pub trait FromManifest: Asset + Sized {
+    /// Path of the manifest file, relative to the assets folder.
+    const PATH: &'static str;
+
+    fn from_manifest(manifest: &AssetManifest) -> Result<Self, ManifestError>;
+}
+
+/// Handles for every asset listed in a manifest, grouped by name.
+#[derive(Debug, Default)]
+pub struct AssetManifest {
+    images: HashMap<String, Vec<Handle<Image>>>,
+    layouts: HashMap<String, Handle<TextureAtlasLayout>>,
+    sounds: HashMap<String, Vec<Handle<AudioSource>>>,
+}
+
+impl AssetManifest {
+    /// The first image named `name`.
+    pub fn image(&self, name: &str) -> Result<Handle<Image>, ManifestError> {
+        first(&self.images, name)
+    }
+
+    /// All images named `name`, in manifest order.
+    pub fn images(&self, name: &str) -> Result<Vec<Handle<Image>>, ManifestError> {
+        all(&self.images, name)
+    }
+
+    /// The atlas layout declared on the image named `name`.
+    pub fn layout(&self, name: &str) -> Result<Handle<TextureAtlasLayout>, ManifestError> {
+        self.layouts
+            .get(name)
+            .cloned()
+            .ok_or_else(|| ManifestError::Missing(name.to_string()))
+    }
+
+    /// The first sound named `name`.
+    pub fn sound(&self, name: &str) -> Result<Handle<AudioSource>, ManifestError> {
+        first(&self.sounds, name)
+    }
+
+    /// All sounds named `name`, in manifest order.
+    pub fn sounds(&self, name: &str) -> Result<Vec<Handle<AudioSource>>, ManifestError> {
+        all(&self.sounds, name)
+    }
+}
+
+fn first<A: Asset>(
+    map: &HashMap<String, Vec<Handle<A>>>,
+    name: &str,
+) -> Result<Handle<A>, ManifestError> {
+    map.get(name)
+        .and_then(|handles| handles.first())
+        .cloned()
+        .ok_or_else(|| ManifestError::Missing(name.to_string()))
+}
+
+fn all<A: Asset>(
+    map: &HashMap<String, Vec<Handle<A>>>,
+    name: &str,
+) -> Result<Vec<Handle<A>>, ManifestError> {
+    map.get(name)
+        .cloned()
+        .ok_or_else(|| ManifestError::Missing(name.to_string()))
+}
+
+#[derive(Debug)]
+pub enum ManifestError {
+    Io(std::io::Error),
+    Parse {
+        line: usize,
+        message: String,
+    },
+    /// The resource asked for a name the manifest doesn't list
+    Missing(String),
+}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "could not read manifest: {error}"),
+            Self::Parse { line, message } => write!(f, "line {line}: {message}"),
+            Self::Missing(name) => write!(f, "no asset named `{name}` in manifest"),
+        }
+    }
+}
+
+impl Error for ManifestError {}
+
+impl From<std::io::Error> for ManifestError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// Loads a [`FromManifest`] resource from its manifest file.
+#[derive(TypePath)]
+pub struct ManifestLoader<T: FromManifest>(PhantomData<fn() -> T>);
+
+impl<T: FromManifest> Default for ManifestLoader<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: FromManifest> AssetLoader for ManifestLoader<T> {
+    type Asset = T;
+    type Settings = ();
+    type Error = ManifestError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<T, ManifestError> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text).await?;
+        let manifest = parse(&text, load_context)?;
+        T::from_manifest(&manifest)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["manifest"]
+    }
+}
+
+fn parse(text: &str, load_context: &mut LoadContext) -> Result<AssetManifest, ManifestError> {
+    let mut manifest = AssetManifest::default();
+
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let error = |message: String| ManifestError::Parse {
+            line: line_number,
+            message,
+        };
+
+        let line = strip_comment(line).trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let mut words = words(line);
+        let (Some(kind), Some(name), Some(path)) = (words.next(), words.next(), words.next())
+        else {
+            return Err(error("expected `<kind> <name> <path> [options]`".into()));
+        };
+        let name = name.to_string();
+        let path = path.to_string();
+
+        match kind {
+            "image" => {
+                let mut nearest = false;
+                for option in words {
+                    if option == "nearest" {
+                        nearest = true;
+                    } else if let Some(grid) = option.strip_prefix("atlas=") {
+                        let layout = parse_atlas(grid).ok_or_else(|| {
+                            error(format!(
+                                "invalid atlas `{grid}`, expected `<width>x<height>:<columns>x<rows>`"
+                            ))
+                        })?;
+                        let handle = load_context.add_labeled_asset(name.clone(), layout);
+                        manifest.layouts.insert(name.clone(), handle);
+                    } else {
+                        return Err(error(format!("unknown image option `{option}`")));
+                    }
+                }
+
+                let image = if nearest {
+                    load_context
+                        .loader()
+                        .with_settings(|settings: &mut ImageLoaderSettings| {
+                            settings.sampler = ImageSampler::nearest();
+                        })
+                        .load(path)
+                } else {
+                    load_context.load(path)
+                };
+                manifest.images.entry(name).or_default().push(image);
+            }
+            "sound" => {
+                let sound = load_context.load(path);
+                manifest.sounds.entry(name).or_default().push(sound);
+            }
+            _ => return Err(error(format!("unknown asset kind `{kind}`"))),
+        }
+    }
+
+    Ok(manifest)
+}
+
+/// Cut off a `#` comment, unless the `#` is inside a quoted path.
+fn strip_comment(line: &str) -> &str {
+    let mut quoted = false;
+    for (index, char) in line.char_indices() {
+        match char {
+            '"' => quoted = !quoted,
+            '#' if !quoted => return &line[..index],
+            _ => {}
+        }
+    }
+    line
+}
+
+/// Split a line on whitespace, keeping double quoted words together.
+fn words(line: &str) -> impl Iterator<Item = &str> {
+    let mut rest = line.trim_start();
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let (word, remaining) = match rest.strip_prefix('"') {
+            Some(quoted) => quoted.split_once('"').unwrap_or((quoted, "")),
+            None => rest.split_once(char::is_whitespace).unwrap_or((rest, "")),
+        };
+        rest = remaining.trim_start();
+        Some(word)
+    })
+}
+
+/// Parse `<width>x<height>:<columns>x<rows>` into a grid layout.
+fn parse_atlas(grid: &str) -> Option<TextureAtlasLayout> {
+    let (tile, count) = grid.split_once(':')?;
+    let (width, height) = tile.split_once('x')?;
+    let (columns, rows) = count.split_once('x')?;
+    Some(TextureAtlasLayout::from_grid(
+        UVec2::new(width.parse().ok()?, height.parse().ok()?),
+        columns.parse().ok()?,
+        rows.parse().ok()?,
+        None,
+        None,
+    ))
+}
@@ -1,8 +1,14 @@
 //! A high-level way to load collections of asset handles as resources.
 
-use std::collections::VecDeque;
+use std::{any::TypeId, collections::VecDeque};
 
-use bevy::prelude::*;
+use bevy::{
+    asset::{LoadState, RecursiveDependencyLoadState, UntypedAssetId},
+    prelude::*,
+    utils::prelude::ShortName,
+};
+
+use crate::asset_manifest::FromManifest;
 
 pub(super) fn plugin(app: &mut App) {
     app.init_resource::<ResourceHandles>();
@@ -19,32 +25,106 @@ pub trait LoadResource {
 impl LoadResource for App {
     fn load_resource<T: Resource + Asset + Clone + FromWorld>(&mut self) -> &mut Self {
         self.init_asset::<T>();
-        let world = self.world_mut();
-        let value = T::from_world(world);
-        let assets = world.resource::<AssetServer>();
-        let handle = assets.add(value);
-        let mut handles = world.resource_mut::<ResourceHandles>();
-        handles
-            .waiting
-            .push_back((handle.untyped(), |world, handle| {
-                let assets = world.resource::<Assets<T>>();
-                if let Some(value) = assets.get(handle.id().typed::<T>()) {
-                    world.insert_resource(value.clone());
-                }
-            }));
+        queue_resource::<T>(self.world_mut(), false);
         self
     }
 }
 
+/// Start loading a resource at runtime, see [`LoadResource`]. The asset type must already be
+/// initialized with [`App::init_asset`].
+/// An `optional` resource is dropped when any asset dependency fails to load, instead of
+/// blocking [`ResourceHandles::is_all_done`] forever.
+pub fn queue_resource<T: Resource + Asset + Clone + FromWorld>(world: &mut World, optional: bool) {
+    let value = T::from_world(world);
+    let mut dependencies = Vec::new();
+    value.visit_dependencies(&mut |id| dependencies.push(id));
+    let handle = world.resource::<AssetServer>().add(value);
+    push_waiting(world, handle, dependencies, optional);
+}
+
+/// Like [`queue_resource`], but the resource is loaded from its manifest file.
+/// The [`crate::asset_manifest::ManifestLoader`] for the type must already be registered.
+pub fn queue_manifest_resource<T: Resource + FromManifest + Clone>(
+    world: &mut World,
+    optional: bool,
+) {
+    let handle = world.resource::<AssetServer>().load::<T>(T::PATH);
+    let dependencies = vec![handle.id().untyped()];
+    push_waiting(world, handle, dependencies, optional);
+}
+
+fn push_waiting<T: Resource + Asset + Clone>(
+    world: &mut World,
+    handle: Handle<T>,
+    dependencies: Vec<UntypedAssetId>,
+    optional: bool,
+) {
+    let mut handles = world.resource_mut::<ResourceHandles>();
+    handles.total += dependencies.len();
+    handles.waiting.push_back(WaitingResource {
+        handle: handle.untyped(),
+        type_id: TypeId::of::<T>(),
+        name: ShortName::of::<T>().to_string(),
+        dependencies,
+        optional,
+        reported: false,
+        insert: |world, handle| {
+            let assets = world.resource::<Assets<T>>();
+            if let Some(value) = assets.get(handle.id().typed::<T>()) {
+                world.insert_resource(value.clone());
+            }
+        },
+    });
+}
+
 /// A function that inserts a loaded resource.
 type InsertLoadedResource = fn(&mut World, &UntypedHandle);
 
+struct WaitingResource {
+    handle: UntypedHandle,
+    type_id: TypeId,
+    /// Short type name of the resource, for display
+    name: String,
+    dependencies: Vec<UntypedAssetId>,
+    /// Dropped when a dependency fails instead of waiting forever
+    optional: bool,
+    /// A [`ResourceLoadFailed`] has already been triggered for this resource
+    reported: bool,
+    insert: InsertLoadedResource,
+}
+
+struct FinishedResource {
+    /// Only held to keep the asset loaded
+    #[allow(unused)]
+    handle: UntypedHandle,
+    type_id: TypeId,
+    dependency_count: usize,
+}
+
+/// Triggered once when any asset dependency of a resource failed to load.
+#[derive(Event, Debug, Clone)]
+pub struct ResourceLoadFailed {
+    /// [`TypeId`] of the resource that won't be inserted
+    pub resource: TypeId,
+    pub name: String,
+    /// Paths of the dependencies that failed
+    pub paths: Vec<String>,
+    /// The resource was queued as optional and has been dropped
+    pub optional: bool,
+}
+
 #[derive(Resource, Default)]
 pub struct ResourceHandles {
     // Use a queue for waiting assets so they can be cycled through and moved to
     // `finished` one at a time.
-    waiting: VecDeque<(UntypedHandle, InsertLoadedResource)>,
-    finished: Vec<UntypedHandle>,
+    waiting: VecDeque<WaitingResource>,
+    finished: Vec<FinishedResource>,
+    /// Dependencies of all requested resources
+    total: usize,
+    /// Dependencies that are loaded, updated every frame
+    loaded: usize,
+    /// Paths of dependencies of required resources that failed to load
+    failed: Vec<String>,
 }
 
 impl ResourceHandles {
@@ -52,20 +132,142 @@ impl ResourceHandles {
     pub fn is_all_done(&self) -> bool {
         self.waiting.is_empty()
     }
+
+    /// Number of asset dependencies that have finished loading.
+    pub fn loaded_count(&self) -> usize {
+        self.loaded
+    }
+
+    /// Number of asset dependencies of all requested resources.
+    pub fn total_count(&self) -> usize {
+        self.total
+    }
+
+    /// Fraction of dependencies loaded, in [0.0, 1.0].
+    pub fn progress(&self) -> f32 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.loaded as f32 / self.total as f32
+        }
+    }
+
+    /// Type names of the resources that are still waiting on their assets.
+    pub fn waiting_names(&self) -> impl Iterator<Item = &str> {
+        self.waiting.iter().map(|waiting| waiting.name.as_str())
+    }
+
+    /// Stop tracking a resource so its assets can be freed once nothing else uses them.
+    /// The resource itself has to be removed from the world by the caller.
+    pub fn release(&mut self, resource: TypeId) {
+        let mut released = 0;
+        self.waiting.retain(|waiting| {
+            let keep = waiting.type_id != resource;
+            if !keep {
+                released += waiting.dependencies.len();
+            }
+            keep
+        });
+        self.finished.retain(|finished| {
+            let keep = finished.type_id != resource;
+            if !keep {
+                released += finished.dependency_count;
+            }
+            keep
+        });
+        self.total -= released;
+    }
+
+    /// Paths of assets that failed to load and that a required resource depends on.
+    /// These resources will never be inserted.
+    pub fn failed_paths(&self) -> &[String] {
+        &self.failed
+    }
 }
 
 fn load_resource_assets(world: &mut World) {
+    let mut failures = Vec::new();
+
     world.resource_scope(|world, mut resource_handles: Mut<ResourceHandles>| {
         world.resource_scope(|world, assets: Mut<AssetServer>| {
             for _ in 0..resource_handles.waiting.len() {
-                let (handle, insert_fn) = resource_handles.waiting.pop_front().unwrap();
-                if assets.is_loaded_with_dependencies(&handle) {
-                    insert_fn(world, &handle);
-                    resource_handles.finished.push(handle);
+                let mut waiting = resource_handles.waiting.pop_front().unwrap();
+                if assets.is_loaded_with_dependencies(&waiting.handle) {
+                    (waiting.insert)(world, &waiting.handle);
+                    resource_handles.finished.push(FinishedResource {
+                        handle: waiting.handle,
+                        type_id: waiting.type_id,
+                        dependency_count: waiting.dependencies.len(),
+                    });
+                    continue;
+                }
+
+                let paths = failed_dependency_paths(&assets, &waiting.dependencies);
+                if paths.is_empty() {
+                    resource_handles.waiting.push_back(waiting);
+                    continue;
+                }
+
+                if !waiting.reported {
+                    waiting.reported = true;
+                    error!("Failed to load {}: {}", waiting.name, paths.join(", "));
+                    failures.push(ResourceLoadFailed {
+                        resource: waiting.type_id,
+                        name: waiting.name.clone(),
+                        paths,
+                        optional: waiting.optional,
+                    });
+                }
+
+                if waiting.optional {
+                    // Don't count it towards progress any more
+                    resource_handles.total -= waiting.dependencies.len();
                 } else {
-                    resource_handles.waiting.push_back((handle, insert_fn));
+                    resource_handles.waiting.push_back(waiting);
+                }
+            }
+
+            let mut loaded = resource_handles.total;
+            let mut failed = Vec::new();
+            for waiting in &resource_handles.waiting {
+                for path in failed_dependency_paths(&assets, &waiting.dependencies) {
+                    if !failed.contains(&path) {
+                        failed.push(path);
+                    }
                 }
+                loaded -= waiting
+                    .dependencies
+                    .iter()
+                    .filter(|&&dependency| !assets.is_loaded_with_dependencies(dependency))
+                    .count();
             }
+            resource_handles.loaded = loaded;
+            resource_handles.failed = failed;
         });
     });
+
+    for failure in failures {
+        world.trigger(failure);
+    }
+}
+
+fn failed_dependency_paths(assets: &AssetServer, dependencies: &[UntypedAssetId]) -> Vec<String> {
+    dependencies
+        .iter()
+        .filter_map(|&dependency| {
+            let path = assets
+                .get_path(dependency)
+                .map_or_else(|| format!("{dependency:?}"), |path| path.to_string());
+            if let Some(LoadState::Failed(_)) = assets.get_load_state(dependency) {
+                Some(path)
+            } else if let Some(RecursiveDependencyLoadState::Failed(error)) =
+                assets.get_recursive_dependency_load_state(dependency)
+            {
+                // Something the dependency loaded itself failed, e.g. a file listed in a manifest
+                Some(format!("{path} ({error})"))
+            } else {
+                None
+            }
+        })
+        .collect()
 }
@@ -1,5 +1,9 @@
+use std::time::Duration;
+
 use bevy::{prelude::*, render::render_resource::AsBindGroup, shader::ShaderRef};
 
+use crate::{accessibility::ReducedMotion, easing::Easing};
+
 pub(super) fn plugin(app: &mut App) {
     app.add_plugins(UiMaterialPlugin::<ProgressBarMaterial>::default())
         .add_systems(Update, (spawn, update));
@@ -7,11 +11,111 @@ pub(super) fn plugin(app: &mut App) {
 
 #[derive(Debug, Default, Component)]
 pub struct ProgressBar {
-    pub vertical: bool,
+    pub fill: Fill,
     pub color: Color,
     pub color_texture: Handle<Image>,
     pub border_color: Color,
     pub progress: f32,
+    /// Drawn over the filled part, so the bar can be told apart without its color
+    pub pattern: Pattern,
+    /// Splits the bar into this many pips that fill one at a time, 0 or 1 for a continuous bar
+    pub segments: u32,
+    /// Part of each pip left empty to separate it from the next, from 0 to 1
+    pub segment_gap: f32,
+    /// Eases the shown fill towards [`Self::progress`] when it changes, instead of jumping
+    pub animation: Option<FillAnimation>,
+}
+
+impl ProgressBar {
+    /// A bar of `segments` pips with `filled` of them filled, such as for lives
+    pub fn pips(segments: u32, filled: u32) -> Self {
+        Self {
+            segments,
+            segment_gap: 0.2,
+            progress: filled as f32 / segments.max(1) as f32,
+            ..default()
+        }
+    }
+}
+
+/// How the filled part of a [`ProgressBar`] grows with its progress
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum Fill {
+    /// Left to right
+    #[default]
+    Horizontal,
+    /// Bottom to top
+    Vertical,
+    /// A circle growing out of the center
+    Radial,
+    /// A wedge sweeping around the center like a clock hand
+    ClockWipe {
+        /// Angle the sweep starts at, in radians clockwise from the top
+        start_angle: f32,
+        clockwise: bool,
+    },
+}
+
+impl Fill {
+    /// Id read by the shader
+    fn id(self) -> f32 {
+        match self {
+            Self::Horizontal => 0.0,
+            Self::Vertical => 1.0,
+            Self::Radial => 2.0,
+            Self::ClockWipe { .. } => 3.0,
+        }
+    }
+}
+
+/// How a [`ProgressBar`] animates to a new progress
+#[derive(Debug, Clone, Copy)]
+pub struct FillAnimation {
+    pub duration: Duration,
+    pub easing: Easing,
+}
+
+impl Default for FillAnimation {
+    fn default() -> Self {
+        Self {
+            duration: Duration::from_millis(300),
+            easing: Easing::QuadOut,
+        }
+    }
+}
+
+/// Progress currently shown by an animated [`ProgressBar`]
+#[derive(Debug, Clone, Copy, Component)]
+struct ShownProgress {
+    from: f32,
+    to: f32,
+    elapsed: Duration,
+    value: f32,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pattern {
+    #[default]
+    Solid,
+    /// Diagonal stripes
+    Stripes,
+    Dots,
+    Checker,
+    /// Horizontal bands
+    Waves,
+}
+
+impl Pattern {
+    /// Id read by the shader
+    fn id(self) -> f32 {
+        match self {
+            Self::Solid => 0.0,
+            Self::Stripes => 1.0,
+            Self::Dots => 2.0,
+            Self::Checker => 3.0,
+            Self::Waves => 4.0,
+        }
+    }
 }
 
 #[derive(AsBindGroup, Asset, TypePath, Debug, Clone)]
@@ -19,9 +123,9 @@ struct ProgressBarMaterial {
     /// Color multiplied with the image
     #[uniform(0)]
     color: Vec4,
-    /// Represents how much of the image is visible
-    /// Goes from 0 to 1
-    /// A `Vec4` is used here because Bevy with webgl2 requires that uniforms are 16-byte aligned but only the first component is read.
+    /// `x` is how much of the image is visible, from 0 to 1
+    /// `y` is the id of the [`Fill`] and `z` the id of the [`Pattern`]
+    /// A `Vec4` is used here because Bevy with webgl2 requires that uniforms are 16-byte aligned.
     #[uniform(1)]
     slider: Vec4,
     /// Image used to represent the slider
@@ -31,6 +135,10 @@ struct ProgressBarMaterial {
     /// Color of the image's border
     #[uniform(4)]
     border_color: Vec4,
+    /// `x` is the start angle and `y` the direction (1 clockwise, -1 counterclockwise) of a
+    /// clock wipe, `z` is the number of segments and `w` the gap between them
+    #[uniform(5)]
+    shape: Vec4,
 }
 
 impl UiMaterial for ProgressBarMaterial {
@@ -52,25 +160,65 @@ fn spawn(
                 slider: Vec4::splat(0.0),
                 color_texture: progress_bar.color_texture.clone(),
                 border_color: progress_bar.border_color.to_linear().to_vec4(),
-            })));
+                shape: Vec4::ZERO,
+            })))
+            .insert(ShownProgress {
+                from: progress_bar.progress,
+                to: progress_bar.progress,
+                elapsed: Duration::ZERO,
+                value: progress_bar.progress,
+            });
     }
 }
 
 fn update(
     mut materials: ResMut<Assets<ProgressBarMaterial>>,
-    query: Query<(&ProgressBar, &MaterialNode<ProgressBarMaterial>)>,
+    mut query: Query<(
+        &ProgressBar,
+        &mut ShownProgress,
+        &MaterialNode<ProgressBarMaterial>,
+    )>,
+    time: Res<Time>,
+    reduced_motion: Res<ReducedMotion>,
 ) {
-    for (progress_bar, material) in query.iter() {
+    for (progress_bar, mut shown, material) in query.iter_mut() {
+        // Jump straight to the new progress with reduced motion
+        match progress_bar.animation.filter(|_| !reduced_motion.0) {
+            Some(animation) => {
+                if shown.to != progress_bar.progress {
+                    shown.from = shown.value;
+                    shown.to = progress_bar.progress;
+                    shown.elapsed = Duration::ZERO;
+                }
+                shown.elapsed += time.delta();
+                let ratio = if animation.duration.is_zero() {
+                    1.0
+                } else {
+                    shown.elapsed.as_secs_f32() / animation.duration.as_secs_f32()
+                };
+                shown.value = shown.from.lerp(shown.to, animation.easing.ease(ratio));
+            }
+            None => {
+                shown.to = progress_bar.progress;
+                shown.value = progress_bar.progress;
+            }
+        }
+
         if let Some(material) = materials.get_mut(material) {
             material.color = progress_bar.color.to_linear().to_vec4();
-            if progress_bar.vertical {
-                material.slider.x = 1.0;
-                material.slider.y = progress_bar.progress;
-            } else {
-                material.slider.x = progress_bar.progress;
-                material.slider.y = 1.0;
-            }
+            material.slider.x = shown.value;
+            material.slider.y = progress_bar.fill.id();
+            material.slider.z = progress_bar.pattern.id();
             material.border_color = progress_bar.border_color.to_linear().to_vec4();
+            material.shape = match progress_bar.fill {
+                Fill::ClockWipe {
+                    start_angle,
+                    clockwise,
+                } => Vec4::new(start_angle, if clockwise { 1.0 } else { -1.0 }, 0.0, 0.0),
+                _ => Vec4::ZERO,
+            };
+            material.shape.z = progress_bar.segments as f32;
+            material.shape.w = progress_bar.segment_gap;
         }
     }
 }
@@ -1,6 +1,112 @@
+//! Robert Penner's easing functions. All functions map `0.0..=1.0` to `0.0..=1.0`
+//! (with some overshoot for the back and elastic families).
+//! See <https://easings.net> for a visual reference.
+
 use std::f32::consts::PI;
 
-#[allow(dead_code)]
+use bevy::reflect::Reflect;
+
+/// A selectable easing curve, used by [`crate::tween::Tween`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum Easing {
+    #[default]
+    Linear,
+    QuadIn,
+    QuadOut,
+    QuadInOut,
+    CubicIn,
+    CubicOut,
+    CubicInOut,
+    QuartIn,
+    QuartOut,
+    QuartInOut,
+    QuintIn,
+    QuintOut,
+    QuintInOut,
+    SineIn,
+    SineOut,
+    SineInOut,
+    ExpoIn,
+    ExpoOut,
+    ExpoInOut,
+    CircIn,
+    CircOut,
+    CircInOut,
+    BackIn,
+    BackOut,
+    BackInOut,
+    ElasticIn,
+    ElasticOut,
+    ElasticInOut,
+    BounceIn,
+    BounceOut,
+    BounceInOut,
+}
+
+impl Easing {
+    /// Apply the curve to `x`, which is clamped to `0.0..=1.0`.
+    pub fn ease(self, x: f32) -> f32 {
+        let x = x.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => x,
+            Self::QuadIn => quad_in(x),
+            Self::QuadOut => quad_out(x),
+            Self::QuadInOut => quad_in_out(x),
+            Self::CubicIn => cubic_in(x),
+            Self::CubicOut => cubic_out(x),
+            Self::CubicInOut => cubic_in_out(x),
+            Self::QuartIn => quart_in(x),
+            Self::QuartOut => quart_out(x),
+            Self::QuartInOut => quart_in_out(x),
+            Self::QuintIn => quint_in(x),
+            Self::QuintOut => quint_out(x),
+            Self::QuintInOut => quint_in_out(x),
+            Self::SineIn => sine_in(x),
+            Self::SineOut => sine_out(x),
+            Self::SineInOut => sine_in_out(x),
+            Self::ExpoIn => expo_in(x),
+            Self::ExpoOut => expo_out(x),
+            Self::ExpoInOut => expo_in_out(x),
+            Self::CircIn => circ_in(x),
+            Self::CircOut => circ_out(x),
+            Self::CircInOut => circ_in_out(x),
+            Self::BackIn => back_in(x),
+            Self::BackOut => back_out(x),
+            Self::BackInOut => back_in_out(x),
+            Self::ElasticIn => elastic_in(x),
+            Self::ElasticOut => elastic_out(x),
+            Self::ElasticInOut => elastic_in_out(x),
+            Self::BounceIn => bounce_in(x),
+            Self::BounceOut => bounce_out(x),
+            Self::BounceInOut => bounce_in_out(x),
+        }
+    }
+}
+
+pub fn quad_in(x: f32) -> f32 {
+    x * x
+}
+
+pub fn quad_out(x: f32) -> f32 {
+    1.0 - (1.0 - x) * (1.0 - x)
+}
+
+pub fn quad_in_out(x: f32) -> f32 {
+    if x < 0.5 {
+        2.0 * x * x
+    } else {
+        1.0 - (-2.0 * x + 2.0).powi(2) / 2.0
+    }
+}
+
+pub fn cubic_in(x: f32) -> f32 {
+    x * x * x
+}
+
+pub fn cubic_out(x: f32) -> f32 {
+    1.0 - (1.0 - x).powi(3)
+}
+
 pub fn cubic_in_out(x: f32) -> f32 {
     if x < 0.5 {
         4.0 * x * x * x
@@ -9,7 +115,169 @@ pub fn cubic_in_out(x: f32) -> f32 {
     }
 }
 
-#[allow(dead_code)]
+pub fn quart_in(x: f32) -> f32 {
+    x.powi(4)
+}
+
+pub fn quart_out(x: f32) -> f32 {
+    1.0 - (1.0 - x).powi(4)
+}
+
+pub fn quart_in_out(x: f32) -> f32 {
+    if x < 0.5 {
+        8.0 * x.powi(4)
+    } else {
+        1.0 - (-2.0 * x + 2.0).powi(4) / 2.0
+    }
+}
+
+pub fn quint_in(x: f32) -> f32 {
+    x.powi(5)
+}
+
+pub fn quint_out(x: f32) -> f32 {
+    1.0 - (1.0 - x).powi(5)
+}
+
+pub fn quint_in_out(x: f32) -> f32 {
+    if x < 0.5 {
+        16.0 * x.powi(5)
+    } else {
+        1.0 - (-2.0 * x + 2.0).powi(5) / 2.0
+    }
+}
+
+pub fn sine_in(x: f32) -> f32 {
+    1.0 - ((x * PI) / 2.0).cos()
+}
+
+pub fn sine_out(x: f32) -> f32 {
+    ((x * PI) / 2.0).sin()
+}
+
 pub fn sine_in_out(x: f32) -> f32 {
     -((PI * x).cos() - 1.0) / 2.0
 }
+
+pub fn expo_in(x: f32) -> f32 {
+    if x == 0.0 {
+        0.0
+    } else {
+        2.0_f32.powf(10.0 * x - 10.0)
+    }
+}
+
+pub fn expo_out(x: f32) -> f32 {
+    if x == 1.0 {
+        1.0
+    } else {
+        1.0 - 2.0_f32.powf(-10.0 * x)
+    }
+}
+
+pub fn expo_in_out(x: f32) -> f32 {
+    if x == 0.0 {
+        0.0
+    } else if x == 1.0 {
+        1.0
+    } else if x < 0.5 {
+        2.0_f32.powf(20.0 * x - 10.0) / 2.0
+    } else {
+        (2.0 - 2.0_f32.powf(-20.0 * x + 10.0)) / 2.0
+    }
+}
+
+pub fn circ_in(x: f32) -> f32 {
+    1.0 - (1.0 - x * x).sqrt()
+}
+
+pub fn circ_out(x: f32) -> f32 {
+    (1.0 - (x - 1.0).powi(2)).sqrt()
+}
+
+pub fn circ_in_out(x: f32) -> f32 {
+    if x < 0.5 {
+        (1.0 - (1.0 - (2.0 * x).powi(2)).sqrt()) / 2.0
+    } else {
+        ((1.0 - (-2.0 * x + 2.0).powi(2)).sqrt() + 1.0) / 2.0
+    }
+}
+
+const BACK_C1: f32 = 1.70158;
+const BACK_C2: f32 = BACK_C1 * 1.525;
+const BACK_C3: f32 = BACK_C1 + 1.0;
+
+pub fn back_in(x: f32) -> f32 {
+    BACK_C3 * x * x * x - BACK_C1 * x * x
+}
+
+pub fn back_out(x: f32) -> f32 {
+    1.0 + BACK_C3 * (x - 1.0).powi(3) + BACK_C1 * (x - 1.0).powi(2)
+}
+
+pub fn back_in_out(x: f32) -> f32 {
+    if x < 0.5 {
+        ((2.0 * x).powi(2) * ((BACK_C2 + 1.0) * 2.0 * x - BACK_C2)) / 2.0
+    } else {
+        ((2.0 * x - 2.0).powi(2) * ((BACK_C2 + 1.0) * (x * 2.0 - 2.0) + BACK_C2) + 2.0) / 2.0
+    }
+}
+
+const ELASTIC_C4: f32 = (2.0 * PI) / 3.0;
+const ELASTIC_C5: f32 = (2.0 * PI) / 4.5;
+
+pub fn elastic_in(x: f32) -> f32 {
+    if x == 0.0 || x == 1.0 {
+        x
+    } else {
+        -(2.0_f32.powf(10.0 * x - 10.0)) * ((x * 10.0 - 10.75) * ELASTIC_C4).sin()
+    }
+}
+
+pub fn elastic_out(x: f32) -> f32 {
+    if x == 0.0 || x == 1.0 {
+        x
+    } else {
+        2.0_f32.powf(-10.0 * x) * ((x * 10.0 - 0.75) * ELASTIC_C4).sin() + 1.0
+    }
+}
+
+pub fn elastic_in_out(x: f32) -> f32 {
+    if x == 0.0 || x == 1.0 {
+        x
+    } else if x < 0.5 {
+        -(2.0_f32.powf(20.0 * x - 10.0) * ((20.0 * x - 11.125) * ELASTIC_C5).sin()) / 2.0
+    } else {
+        (2.0_f32.powf(-20.0 * x + 10.0) * ((20.0 * x - 11.125) * ELASTIC_C5).sin()) / 2.0 + 1.0
+    }
+}
+
+pub fn bounce_in(x: f32) -> f32 {
+    1.0 - bounce_out(1.0 - x)
+}
+
+pub fn bounce_out(x: f32) -> f32 {
+    const N1: f32 = 7.5625;
+    const D1: f32 = 2.75;
+
+    if x < 1.0 / D1 {
+        N1 * x * x
+    } else if x < 2.0 / D1 {
+        let x = x - 1.5 / D1;
+        N1 * x * x + 0.75
+    } else if x < 2.5 / D1 {
+        let x = x - 2.25 / D1;
+        N1 * x * x + 0.9375
+    } else {
+        let x = x - 2.625 / D1;
+        N1 * x * x + 0.984375
+    }
+}
+
+pub fn bounce_in_out(x: f32) -> f32 {
+    if x < 0.5 {
+        (1.0 - bounce_out(1.0 - 2.0 * x)) / 2.0
+    } else {
+        (1.0 + bounce_out(2.0 * x - 1.0)) / 2.0
+    }
+}
@@ -3,7 +3,14 @@ use std::time::Duration;
 use bevy::prelude::*;
 use rand::Rng;
 
-use crate::{float::Floats, games::GameData, random};
+use crate::{
+    accessibility::ReducedMotion,
+    easing::Easing,
+    float::Floats,
+    games::GameData,
+    random,
+    tween::{TranslationLens, Tween},
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(Update, (spawn, update));
@@ -14,38 +21,60 @@ fn spawn(
     query: Query<(Entity, &Transform), (Added<Floats>, With<Sprite>)>,
 ) {
     for (entity, transform) in query.iter() {
-        commands
-            .entity(entity)
-            .insert(FloatsPositionSpriteData::new(
+        commands.entity(entity).insert((
+            FloatsPositionSpriteData::new(
                 Vec2::new(transform.translation.x, transform.translation.y),
                 transform.translation.z,
-            ));
+            ),
+            // Completes straight away so a target is picked on the next update
+            Tween::new(
+                TranslationLens {
+                    start: transform.translation,
+                    end: transform.translation,
+                },
+                Duration::ZERO,
+                Easing::Linear,
+            ),
+        ));
     }
 }
 
 fn update(
-    mut query: Query<(&mut Transform, &mut FloatsPositionSpriteData), With<Floats>>,
+    mut query: Query<(&mut Tween<TranslationLens>, &mut FloatsPositionSpriteData), With<Floats>>,
     data: Res<GameData>,
-    time: Res<Time>,
+    reduced_motion: Res<ReducedMotion>,
 ) {
     let mut rng = rand::rng();
-    for (mut transform, mut floats_data) in query.iter_mut() {
-        if floats_data.expired(time.elapsed()) {
-            // Generate new target position
-            floats_data.move_start = floats_data.target;
-            // Use the sign of the last target to make sure we always rotate the other way
+    for (mut tween, mut floats_data) in query.iter_mut() {
+        if !tween.is_completed() {
+            continue;
+        }
+
+        // Generate new target position
+        let move_start = floats_data.target;
+        if reduced_motion.0 {
+            // Settle back where it started and stay there
+            if move_start == Vec2::ZERO {
+                continue;
+            }
+            floats_data.target = Vec2::ZERO;
+        } else {
             floats_data.target = Vec2::new(
                 rng.random_range(1.0..5.0) * random::sign(&mut rng),
                 rng.random_range(1.0..5.0) * random::sign(&mut rng),
             );
-            floats_data.move_start_time = time.elapsed();
-            let speed = rng.random_range(1.0..5.0) * data.fever_grade().max(1.0);
-            floats_data.move_duration =
-                Duration::from_secs_f32(floats_data.move_distance() / speed);
-        } else {
-            transform.translation =
-                (floats_data.start + floats_data.lerp(time.elapsed())).extend(floats_data.order);
         }
+        let speed = rng.random_range(1.0..5.0) * data.fever_grade().max(1.0);
+        let duration = Duration::from_secs_f32(move_start.distance(floats_data.target) / speed);
+
+        *tween = Tween::new(
+            TranslationLens {
+                start: (floats_data.start + move_start).extend(floats_data.order),
+                end: (floats_data.start + floats_data.target).extend(floats_data.order),
+            },
+            duration,
+            Easing::SineInOut,
+        );
     }
 }
 
@@ -53,9 +82,6 @@ fn update(
 struct FloatsPositionSpriteData {
     start: Vec2,
     target: Vec2,
-    move_start: Vec2,
-    move_start_time: Duration,
-    move_duration: Duration,
     order: f32,
 }
 
@@ -64,29 +90,7 @@ impl FloatsPositionSpriteData {
         Self {
             start,
             target: Vec2::ZERO,
-            move_start: Vec2::ZERO,
-            move_start_time: Duration::default(),
-            move_duration: Duration::default(),
             order,
         }
     }
-
-    pub fn expired(&self, time: Duration) -> bool {
-        time > self.move_start_time + self.move_duration
-    }
-
-    pub fn t(&self, time: Duration) -> f32 {
-        ((time.as_secs_f32() - self.move_start_time.as_secs_f32())
-            / self.move_duration.as_secs_f32())
-        .clamp(0.0, 1.0)
-    }
-
-    pub fn lerp(&self, time: Duration) -> Vec2 {
-        self.move_start
-            .lerp(self.target, crate::easing::sine_in_out(self.t(time)))
-    }
-
-    pub fn move_distance(&self) -> f32 {
-        self.move_start.distance(self.target)
-    }
 }
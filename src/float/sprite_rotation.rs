@@ -3,7 +3,13 @@ use std::time::Duration;
 use bevy::prelude::*;
 use rand::Rng;
 
-use crate::{float::Floats, games::GameData};
+use crate::{
+    accessibility::ReducedMotion,
+    easing::Easing,
+    float::Floats,
+    games::GameData,
+    tween::{RotationLens, Tween},
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(Update, (spawn, update));
@@ -14,36 +20,56 @@ fn spawn(
     query: Query<(Entity, &Transform), (Added<Floats>, With<Sprite>)>,
 ) {
     for (entity, transform) in query.iter() {
-        commands
-            .entity(entity)
-            .insert(FloatsRotationSpriteData::new(
+        commands.entity(entity).insert((
+            FloatsRotationSpriteData::new(
                 transform.rotation.to_euler(EulerRot::YXZ).2.to_degrees(),
-            ));
+            ),
+            // Completes straight away so a target is picked on the next update
+            Tween::new(
+                RotationLens {
+                    start: transform.rotation,
+                    end: transform.rotation,
+                },
+                Duration::ZERO,
+                Easing::Linear,
+            ),
+        ));
     }
 }
 
 fn update(
-    mut query: Query<(&mut Transform, &mut FloatsRotationSpriteData), With<Floats>>,
+    mut query: Query<(&mut Tween<RotationLens>, &mut FloatsRotationSpriteData), With<Floats>>,
     data: Res<GameData>,
-    time: Res<Time>,
+    reduced_motion: Res<ReducedMotion>,
 ) {
     let mut rng = rand::rng();
-    for (mut transform, mut floats_data) in query.iter_mut() {
-        if floats_data.expired(time.elapsed()) {
-            // Generate new target position
-            floats_data.move_start = floats_data.target;
+    for (mut tween, mut floats_data) in query.iter_mut() {
+        if !tween.is_completed() {
+            continue;
+        }
+
+        let move_start = floats_data.target;
+        if reduced_motion.0 {
+            // Settle back where it started and stay there
+            if move_start == 0.0 {
+                continue;
+            }
+            floats_data.target = 0.0;
+        } else {
             // Use the sign of the last target to make sure we always rotate the other way
             floats_data.target = rng.random_range(0.0..2.0) * -floats_data.target.signum();
-            floats_data.move_start_time = time.elapsed();
-            let speed = rng.random_range(2.0..3.0) * data.fever_grade().max(1.0); // deg/s
-            floats_data.move_duration =
-                Duration::from_secs_f32(floats_data.move_distance() / speed);
-        } else {
-            transform.rotation = Quat::from_axis_angle(
-                Vec3::Z,
-                (floats_data.start + floats_data.lerp(time.elapsed())).to_radians(),
-            );
         }
+        let speed = rng.random_range(2.0..3.0) * data.fever_grade().max(1.0); // deg/s
+        let duration = Duration::from_secs_f32((move_start - floats_data.target).abs() / speed);
+
+        *tween = Tween::new(
+            RotationLens {
+                start: floats_data.rotation(move_start),
+                end: floats_data.rotation(floats_data.target),
+            },
+            duration,
+            Easing::SineInOut,
+        );
     }
 }
 
@@ -51,38 +77,15 @@ fn update(
 struct FloatsRotationSpriteData {
     start: f32,
     target: f32,
-    move_start: f32,
-    move_start_time: Duration,
-    move_duration: Duration,
 }
 
 impl FloatsRotationSpriteData {
     pub fn new(start: f32) -> Self {
-        Self {
-            start,
-            target: 0.0,
-            move_start: 0.0,
-            move_start_time: Duration::default(),
-            move_duration: Duration::default(),
-        }
-    }
-
-    pub fn expired(&self, time: Duration) -> bool {
-        time > self.move_start_time + self.move_duration
-    }
-
-    pub fn t(&self, time: Duration) -> f32 {
-        ((time.as_secs_f32() - self.move_start_time.as_secs_f32())
-            / self.move_duration.as_secs_f32())
-        .clamp(0.0, 1.0)
-    }
-
-    pub fn lerp(&self, time: Duration) -> f32 {
-        self.move_start
-            .lerp(self.target, crate::easing::sine_in_out(self.t(time)))
+        Self { start, target: 0.0 }
     }
 
-    pub fn move_distance(&self) -> f32 {
-        (self.move_start - self.target).abs()
+    /// Rotation for an offset in degrees from the starting angle
+    fn rotation(&self, offset: f32) -> Quat {
+        Quat::from_axis_angle(Vec3::Z, (self.start + offset).to_radians())
     }
 }
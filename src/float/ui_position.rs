@@ -3,7 +3,14 @@ use std::time::Duration;
 use bevy::{prelude::*, window::PrimaryWindow};
 use rand::Rng;
 
-use crate::{float::Floats, games::GameData, random};
+use crate::{
+    accessibility::ReducedMotion,
+    easing::Easing,
+    float::Floats,
+    games::GameData,
+    random,
+    tween::{Tween, UiTranslationLens},
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(Update, (spawn, update));
@@ -15,38 +22,58 @@ fn spawn(
     window: Single<&Window, With<PrimaryWindow>>,
 ) {
     for (entity, transform) in query.iter() {
-        commands
-            .entity(entity)
-            .insert((FloatsPositionUiData::new(transform.translation.resolve(
-                window.scale_factor(),
-                window.size(),
-                window.size(),
-            )),));
+        let start =
+            transform
+                .translation
+                .resolve(window.scale_factor(), window.size(), window.size());
+        commands.entity(entity).insert((
+            FloatsPositionUiData::new(start),
+            // Completes straight away so a target is picked on the next update
+            Tween::new(
+                UiTranslationLens { start, end: start },
+                Duration::ZERO,
+                Easing::Linear,
+            ),
+        ));
     }
 }
 
 fn update(
-    mut query: Query<(&mut UiTransform, &mut FloatsPositionUiData), With<Floats>>,
+    mut query: Query<(&mut Tween<UiTranslationLens>, &mut FloatsPositionUiData), With<Floats>>,
     data: Res<GameData>,
-    time: Res<Time>,
+    reduced_motion: Res<ReducedMotion>,
 ) {
     let mut rng = rand::rng();
-    for (mut transform, mut floats_data) in query.iter_mut() {
-        if floats_data.expired(time.elapsed()) {
-            // Generate new target position
-            floats_data.move_start = floats_data.target; // Assume we met target. Saves using `resolve()`
+    for (mut tween, mut floats_data) in query.iter_mut() {
+        if !tween.is_completed() {
+            continue;
+        }
+
+        // Generate new target position
+        let move_start = floats_data.target;
+        if reduced_motion.0 {
+            // Settle back where it started and stay there
+            if move_start == Vec2::ZERO {
+                continue;
+            }
+            floats_data.target = Vec2::ZERO;
+        } else {
             floats_data.target = Vec2::new(
                 rng.random_range(2.0..10.0) * random::sign(&mut rng),
                 rng.random_range(2.0..10.0) * random::sign(&mut rng),
             );
-            floats_data.move_start_time = time.elapsed();
-            let speed = rng.random_range(5.0..10.0) * data.fever_grade().max(1.0); // px/s
-            floats_data.move_duration =
-                Duration::from_secs_f32(floats_data.move_distance() / speed);
-        } else {
-            let pos = floats_data.start + floats_data.lerp(time.elapsed());
-            transform.translation = Val2::px(pos.x, pos.y);
         }
+        let speed = rng.random_range(5.0..10.0) * data.fever_grade().max(1.0); // px/s
+        let duration = Duration::from_secs_f32(move_start.distance(floats_data.target) / speed);
+
+        *tween = Tween::new(
+            UiTranslationLens {
+                start: floats_data.start + move_start,
+                end: floats_data.start + floats_data.target,
+            },
+            duration,
+            Easing::SineInOut,
+        );
     }
 }
 
@@ -54,9 +81,6 @@ fn update(
 struct FloatsPositionUiData {
     start: Vec2,
     target: Vec2,
-    move_start: Vec2,
-    move_start_time: Duration,
-    move_duration: Duration,
 }
 
 impl FloatsPositionUiData {
@@ -64,28 +88,6 @@ impl FloatsPositionUiData {
         Self {
             start,
             target: Vec2::ZERO,
-            move_start: Vec2::ZERO,
-            move_start_time: Duration::default(),
-            move_duration: Duration::default(),
         }
     }
-
-    pub fn expired(&self, time: Duration) -> bool {
-        time > self.move_start_time + self.move_duration
-    }
-
-    pub fn t(&self, time: Duration) -> f32 {
-        ((time.as_secs_f32() - self.move_start_time.as_secs_f32())
-            / self.move_duration.as_secs_f32())
-        .clamp(0.0, 1.0)
-    }
-
-    pub fn lerp(&self, time: Duration) -> Vec2 {
-        self.move_start
-            .lerp(self.target, crate::easing::sine_in_out(self.t(time)))
-    }
-
-    pub fn move_distance(&self) -> f32 {
-        self.move_start.distance(self.target)
-    }
 }
@@ -3,7 +3,13 @@ use std::time::Duration;
 use bevy::prelude::*;
 use rand::Rng;
 
-use crate::{float::Floats, games::GameData};
+use crate::{
+    accessibility::ReducedMotion,
+    easing::Easing,
+    float::Floats,
+    games::GameData,
+    tween::{Tween, UiRotationLens},
+};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(Update, (spawn, update));
@@ -11,33 +17,57 @@ pub(super) fn plugin(app: &mut App) {
 
 fn spawn(mut commands: Commands, query: Query<(Entity, &UiTransform), Added<Floats>>) {
     for (entity, transform) in query.iter() {
-        commands
-            .entity(entity)
-            .insert((FloatsRotationUiData::new(transform.rotation),));
+        commands.entity(entity).insert((
+            FloatsRotationUiData::new(transform.rotation),
+            // Completes straight away so a target is picked on the next update
+            Tween::new(
+                UiRotationLens {
+                    start: transform.rotation,
+                    end: transform.rotation,
+                },
+                Duration::ZERO,
+                Easing::Linear,
+            ),
+        ));
     }
 }
 
 fn update(
-    mut query: Query<(&mut UiTransform, &mut FloatsRotationUiData), With<Floats>>,
+    mut query: Query<(&mut Tween<UiRotationLens>, &mut FloatsRotationUiData), With<Floats>>,
     data: Res<GameData>,
-    time: Res<Time>,
+    reduced_motion: Res<ReducedMotion>,
 ) {
     let mut rng = rand::rng();
-    for (mut transform, mut floats_data) in query.iter_mut() {
-        if floats_data.expired(time.elapsed()) {
-            // Generate new target position
-            floats_data.move_start = floats_data.target;
+    for (mut tween, mut floats_data) in query.iter_mut() {
+        if !tween.is_completed() {
+            continue;
+        }
+
+        let move_start = floats_data.target;
+        if reduced_motion.0 {
+            // Settle back where it started and stay there
+            if move_start == Rot2::IDENTITY {
+                continue;
+            }
+            floats_data.target = Rot2::IDENTITY;
+        } else {
             // Use the sign of the last target to make sure we always rotate the other way
             floats_data.target = Rot2::degrees(
                 rng.random_range(0.0..2.0) * -floats_data.target.as_degrees().signum(),
             );
-            floats_data.move_start_time = time.elapsed();
-            let speed = rng.random_range(2.0..3.0) * data.fever_grade(); // deg/s
-            floats_data.move_duration =
-                Duration::from_secs_f32(floats_data.move_distance() / speed.max(1.0));
-        } else {
-            transform.rotation = floats_data.start * floats_data.slerp(time.elapsed());
         }
+        let speed = rng.random_range(2.0..3.0) * data.fever_grade(); // deg/s
+        let distance = move_start.angle_to(floats_data.target).to_degrees().abs();
+        let duration = Duration::from_secs_f32(distance / speed.max(1.0));
+
+        *tween = Tween::new(
+            UiRotationLens {
+                start: floats_data.start * move_start,
+                end: floats_data.start * floats_data.target,
+            },
+            duration,
+            Easing::SineInOut,
+        );
     }
 }
 
@@ -45,9 +75,6 @@ fn update(
 struct FloatsRotationUiData {
     start: Rot2,
     target: Rot2,
-    move_start: Rot2,
-    move_start_time: Duration,
-    move_duration: Duration,
 }
 
 impl FloatsRotationUiData {
@@ -55,28 +82,6 @@ impl FloatsRotationUiData {
         Self {
             start,
             target: Rot2::default(),
-            move_start: Rot2::default(),
-            move_start_time: Duration::default(),
-            move_duration: Duration::default(),
         }
     }
-
-    pub fn expired(&self, time: Duration) -> bool {
-        time > self.move_start_time + self.move_duration
-    }
-
-    pub fn t(&self, time: Duration) -> f32 {
-        ((time.as_secs_f32() - self.move_start_time.as_secs_f32())
-            / self.move_duration.as_secs_f32())
-        .clamp(0.0, 1.0)
-    }
-
-    pub fn slerp(&self, time: Duration) -> Rot2 {
-        self.move_start
-            .slerp(self.target, crate::easing::sine_in_out(self.t(time)))
-    }
-
-    pub fn move_distance(&self) -> f32 {
-        (self.move_start.angle_to(self.target).to_degrees()).abs()
-    }
 }
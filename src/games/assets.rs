@@ -0,0 +1,157 @@
+//! On demand loading of the assets of each game.
+//!
+//! Game assets are registered with [`LoadGameResource::load_game_manifest`] but only start
+//! loading when [`load_game_assets`] is queued, which happens while the pre game countdown
+//! runs. Assets of games that haven't been played for a while are released again.
+
+use std::any::TypeId;
+
+use bevy::{
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+
+use crate::{
+    asset_manifest::{FromManifest, ManifestLoader},
+    asset_tracking::{ResourceHandles, ResourceLoadFailed, queue_manifest_resource},
+    games::{GAME_POOL, Game, GameData, balance},
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<GameAssetRegistry>();
+    app.init_resource::<FailedGames>();
+    app.add_observer(disable_failed_game);
+}
+
+/// Games whose assets failed to load, these are never added to the game pool
+#[derive(Debug, Default, Resource)]
+pub struct FailedGames(pub HashSet<Game>);
+
+#[derive(Debug, Clone, Copy)]
+struct GameAssetLoader {
+    resource: TypeId,
+    load: fn(&mut World),
+    is_loaded: fn(&World) -> bool,
+    unload: fn(&mut World),
+}
+
+#[derive(Debug, Default, Resource)]
+struct GameAssetRegistry {
+    loaders: HashMap<Game, Vec<GameAssetLoader>>,
+    /// Games with loaded or loading assets, and the round they were last requested in
+    last_used: HashMap<Game, usize>,
+}
+
+impl GameAssetRegistry {
+    fn owner(&self, resource: TypeId) -> Option<Game> {
+        self.loaders.iter().find_map(|(&game, loaders)| {
+            loaders
+                .iter()
+                .any(|loader| loader.resource == resource)
+                .then_some(game)
+        })
+    }
+}
+
+pub trait LoadGameResource {
+    /// Register the assets of a game, built from the manifest file at [`FromManifest::PATH`].
+    /// They are only loaded once the game is coming up, and if they fail to load the game is
+    /// removed from the pool instead of blocking the app.
+    fn load_game_manifest<T: Resource + FromManifest + Clone>(&mut self, game: Game) -> &mut Self;
+}
+
+impl LoadGameResource for App {
+    fn load_game_manifest<T: Resource + FromManifest + Clone>(&mut self, game: Game) -> &mut Self {
+        self.init_asset::<T>();
+        self.register_asset_loader(ManifestLoader::<T>::default());
+        register_loader::<T>(self, game, |world| {
+            queue_manifest_resource::<T>(world, true)
+        });
+        self
+    }
+}
+
+fn register_loader<T: Resource>(app: &mut App, game: Game, load: fn(&mut World)) {
+    app.world_mut()
+        .get_resource_or_init::<GameAssetRegistry>()
+        .loaders
+        .entry(game)
+        .or_default()
+        .push(GameAssetLoader {
+            resource: TypeId::of::<T>(),
+            load,
+            is_loaded: |world| world.contains_resource::<T>(),
+            unload: |world| {
+                world.remove_resource::<T>();
+                world
+                    .resource_mut::<ResourceHandles>()
+                    .release(TypeId::of::<T>());
+            },
+        });
+}
+
+/// Start loading the assets of `game` if needed, and release the assets of games
+/// that haven't been played for [`balance::UNLOAD_GAME_ASSETS_AFTER_POOLS`] passes through the pool.
+pub fn load_game_assets(game: Game) -> impl Command {
+    move |world: &mut World| {
+        let round = world.resource::<GameData>().round;
+        let unload_after = balance::UNLOAD_GAME_ASSETS_AFTER_POOLS * GAME_POOL.len();
+        let mut registry = world.resource_mut::<GameAssetRegistry>();
+        let requested = registry.last_used.insert(game, round).is_some();
+
+        let unused = registry
+            .last_used
+            .iter()
+            .filter(|&(_, &last_used)| round.saturating_sub(last_used) >= unload_after)
+            .map(|(&game, _)| game)
+            .collect::<Vec<_>>();
+        let mut unload = Vec::new();
+        for game in unused {
+            registry.last_used.remove(&game);
+            info!("Unloading assets of {game}");
+            unload.extend(registry.loaders.get(&game).into_iter().flatten().copied());
+        }
+
+        let load = if requested {
+            Vec::new()
+        } else {
+            info!("Loading assets of {game}");
+            registry.loaders.get(&game).cloned().unwrap_or_default()
+        };
+
+        for loader in unload {
+            (loader.unload)(world);
+        }
+        for loader in load {
+            (loader.load)(world);
+        }
+    }
+}
+
+/// Returns true once every asset resource of `game` has been inserted.
+pub fn game_assets_ready(world: &World, game: Game) -> bool {
+    let registry = world.resource::<GameAssetRegistry>();
+    registry
+        .loaders
+        .get(&game)
+        .is_none_or(|loaders| loaders.iter().all(|loader| (loader.is_loaded)(world)))
+}
+
+fn disable_failed_game(
+    failed: On<ResourceLoadFailed>,
+    registry: Res<GameAssetRegistry>,
+    mut failed_games: ResMut<FailedGames>,
+    mut game_data: ResMut<GameData>,
+) {
+    if failed.optional
+        && let Some(game) = registry.owner(failed.resource)
+    {
+        warn!(
+            "Removing {game} from the game pool, {} failed to load: {}",
+            failed.name,
+            failed.paths.join(", ")
+        );
+        failed_games.0.insert(game);
+        game_data.game_pool.retain(|&kind| kind != game);
+    }
+}
@@ -1,3 +1,7 @@
 pub const MAX_FEVER: f32 = 3.0;
 
 pub const ROUNDS_PER_LEVEL: usize = 5;
+
+/// Game assets are unloaded when the game hasn't been played for this many passes through
+/// the game pool. Each game comes up once per pass, so this only releases games that dropped out.
+pub const UNLOAD_GAME_ASSETS_AFTER_POOLS: usize = 2;
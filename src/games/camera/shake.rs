@@ -1,52 +1,205 @@
-use bevy::{math::ops::powf, prelude::*};
+use std::time::Duration;
+
+use bevy::{
+    input::gamepad::{GamepadRumbleIntensity, GamepadRumbleRequest},
+    math::ops::powf,
+    prelude::*,
+};
+
+use crate::{accessibility::ReducedMotion, input::ActiveInput};
 
 const TRAUMA_DECAY_PER_SECOND: f32 = 0.5;
 const TRAUMA_EXPONENT: f32 = 2.0;
 const MAX_ANGLE: f32 = 5.0_f32.to_radians();
 const MAX_TRANSLATION: f32 = 10.0;
 const NOISE_SPEED: f32 = 20.0;
+const KICK_DECAY: f32 = 12.0; // exponential decay rate
+const MAX_KICK: f32 = 20.0;
+const RUMBLE_DECAY_PER_SECOND: f32 = 0.8;
+const MAX_RUMBLE_TRANSLATION: f32 = 3.0;
+const RUMBLE_NOISE_SPEED: f32 = 60.0;
+/// Highest shake intensity with [`ReducedMotion`] on
+const REDUCED_MOTION_MAX_INTENSITY: f32 = 0.25;
 
 // Camera shake plugin, mostly based on https://bevy.org/examples/camera/2d-screen-shake/
 pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<ShakeSettings>();
+    app.add_observer(add_trauma);
     app.add_systems(PreUpdate, reset_transform);
     app.add_systems(PostUpdate, shake_camera.before(TransformSystems::Propagate));
 }
 
+/// Global settings applied to every shaking camera
+#[derive(Debug, Clone, Copy, Resource)]
+pub struct ShakeSettings {
+    /// Scales all camera shake and gamepad rumble. 0.0 turns it off.
+    pub intensity: f32,
+}
+
+impl Default for ShakeSettings {
+    fn default() -> Self {
+        Self { intensity: 1.0 }
+    }
+}
+
+/// The style of shake applied by [`AddTrauma`]
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum ShakePreset {
+    /// Noisy shake and roll in every direction
+    #[default]
+    Noise,
+    /// A sharp push in a direction that springs back, e.g. an impact
+    Kick(Vec2),
+    /// A low, fast wobble without any roll, e.g. something heavy moving
+    Rumble,
+}
+
+/// Add shake to every camera with a [`CameraShakeConfig`]
+#[derive(Debug, Clone, Copy, Event)]
+pub struct AddTrauma {
+    /// How much to add, in [0.0, 1.0]
+    pub amount: f32,
+    pub preset: ShakePreset,
+}
+
+impl AddTrauma {
+    pub fn new(amount: f32) -> Self {
+        Self {
+            amount,
+            preset: ShakePreset::Noise,
+        }
+    }
+
+    pub fn kick(amount: f32, direction: Vec2) -> Self {
+        Self {
+            amount,
+            preset: ShakePreset::Kick(direction),
+        }
+    }
+
+    pub fn rumble(amount: f32) -> Self {
+        Self {
+            amount,
+            preset: ShakePreset::Rumble,
+        }
+    }
+}
+
+fn add_trauma(
+    add: On<AddTrauma>,
+    mut cameras: Query<&mut CameraShakeState, With<CameraShakeConfig>>,
+    settings: Res<ShakeSettings>,
+    active_input: Res<ActiveInput>,
+    mut rumble_requests: MessageWriter<GamepadRumbleRequest>,
+) {
+    for mut camera_shake in cameras.iter_mut() {
+        match add.preset {
+            ShakePreset::Noise => {
+                camera_shake.trauma = (camera_shake.trauma + add.amount).clamp(0.0, 1.0);
+            }
+            ShakePreset::Kick(direction) => {
+                camera_shake.kick += direction.normalize_or_zero() * add.amount;
+                camera_shake.kick = camera_shake.kick.clamp_length_max(1.0);
+            }
+            ShakePreset::Rumble => {
+                camera_shake.rumble = (camera_shake.rumble + add.amount).clamp(0.0, 1.0);
+            }
+        }
+    }
+
+    if let ActiveInput::Gamepad(gamepad) = *active_input {
+        let strength = (add.amount * settings.intensity).clamp(0.0, 1.0);
+        if strength <= 0.0 {
+            return;
+        }
+
+        let (duration, intensity) = match add.preset {
+            ShakePreset::Noise => (
+                Duration::from_secs_f32(add.amount / TRAUMA_DECAY_PER_SECOND),
+                GamepadRumbleIntensity {
+                    strong_motor: strength,
+                    weak_motor: strength,
+                },
+            ),
+            ShakePreset::Kick(_) => (
+                Duration::from_millis(150),
+                GamepadRumbleIntensity::strong_motor(strength),
+            ),
+            ShakePreset::Rumble => (
+                Duration::from_secs_f32(add.amount / RUMBLE_DECAY_PER_SECOND),
+                GamepadRumbleIntensity::weak_motor(strength),
+            ),
+        };
+
+        rumble_requests.write(GamepadRumbleRequest::Add {
+            duration,
+            intensity,
+            gamepad,
+        });
+    }
+}
+
 fn shake_camera(
-    camera_shake: Single<(&mut CameraShakeState, &CameraShakeConfig, &mut Transform)>,
+    mut cameras: Query<(&mut CameraShakeState, &CameraShakeConfig, &mut Transform)>,
+    settings: Res<ShakeSettings>,
+    reduced_motion: Res<ReducedMotion>,
     time: Res<Time>,
 ) {
-    let (mut camera_shake, config, mut transform) = camera_shake.into_inner();
+    let intensity = if reduced_motion.0 {
+        settings.intensity.min(REDUCED_MOTION_MAX_INTENSITY)
+    } else {
+        settings.intensity
+    };
 
-    camera_shake.original_transform = *transform;
+    for (mut camera_shake, config, mut transform) in cameras.iter_mut() {
+        camera_shake.original_transform = *transform;
 
-    let t = time.elapsed_secs() * config.noise_speed;
-    let rotation_noise = perlin_noise::generate(t + 0.0);
-    let x_noise = perlin_noise::generate(t + 100.0);
-    let y_noise = perlin_noise::generate(t + 200.0);
-    let shake = powf(camera_shake.trauma, config.exponent);
+        let t = time.elapsed_secs() * config.noise_speed;
+        let rotation_noise = perlin_noise::generate(t + 0.0);
+        let x_noise = perlin_noise::generate(t + 100.0);
+        let y_noise = perlin_noise::generate(t + 200.0);
+        let shake = powf(camera_shake.trauma, config.exponent) * intensity;
 
-    let roll_offset = rotation_noise * shake * config.max_angle;
-    let x_offset = x_noise * shake * config.max_translation;
-    let y_offset = y_noise * shake * config.max_translation;
+        let roll_offset = rotation_noise * shake * config.max_angle;
+        let x_offset = x_noise * shake * config.max_translation;
+        let y_offset = y_noise * shake * config.max_translation;
 
-    transform.translation.x += x_offset;
-    transform.translation.y += y_offset;
-    transform.rotate_z(roll_offset);
+        transform.translation.x += x_offset;
+        transform.translation.y += y_offset;
+        transform.rotate_z(roll_offset);
 
-    camera_shake.trauma -= config.trauma_decay_per_second * time.delta_secs();
-    camera_shake.trauma = camera_shake.trauma.clamp(0.0, 1.0);
+        // Directional kick
+        let kick = camera_shake.kick * config.max_kick * intensity;
+        transform.translation += kick.extend(0.0);
+
+        // Rumble
+        let t = time.elapsed_secs() * config.rumble_noise_speed;
+        let rumble = camera_shake.rumble * intensity * config.max_rumble_translation;
+        transform.translation.x += perlin_noise::generate(t + 300.0) * rumble;
+        transform.translation.y += perlin_noise::generate(t + 400.0) * rumble;
+
+        camera_shake.trauma -= config.trauma_decay_per_second * time.delta_secs();
+        camera_shake.trauma = camera_shake.trauma.clamp(0.0, 1.0);
+        camera_shake.kick *= f32::exp(-config.kick_decay * time.delta_secs());
+        camera_shake.rumble -= config.rumble_decay_per_second * time.delta_secs();
+        camera_shake.rumble = camera_shake.rumble.clamp(0.0, 1.0);
+    }
 }
 
-fn reset_transform(camera_shake: Single<(&CameraShakeState, &mut Transform)>) {
-    let (camera_shake, mut transform) = camera_shake.into_inner();
-    *transform = camera_shake.original_transform;
+fn reset_transform(mut cameras: Query<(&CameraShakeState, &mut Transform)>) {
+    for (camera_shake, mut transform) in cameras.iter_mut() {
+        *transform = camera_shake.original_transform;
+    }
 }
 
 #[derive(Component, Debug, Default)]
 pub struct CameraShakeState {
     /// The current trauma level in [0.0, 1.0].
-    pub trauma: f32,
+    trauma: f32,
+    /// The current kick offset, length in [0.0, 1.0].
+    kick: Vec2,
+    /// The current rumble level in [0.0, 1.0].
+    rumble: f32,
     original_transform: Transform,
 }
 
@@ -58,6 +211,11 @@ pub struct CameraShakeConfig {
     max_angle: f32,
     max_translation: f32,
     noise_speed: f32,
+    kick_decay: f32,
+    max_kick: f32,
+    rumble_decay_per_second: f32,
+    max_rumble_translation: f32,
+    rumble_noise_speed: f32,
 }
 
 impl Default for CameraShakeConfig {
@@ -68,6 +226,11 @@ impl Default for CameraShakeConfig {
             max_angle: MAX_ANGLE,
             max_translation: MAX_TRANSLATION,
             noise_speed: NOISE_SPEED,
+            kick_decay: KICK_DECAY,
+            max_kick: MAX_KICK,
+            rumble_decay_per_second: RUMBLE_DECAY_PER_SECOND,
+            max_rumble_translation: MAX_RUMBLE_TRANSLATION,
+            rumble_noise_speed: RUMBLE_NOISE_SPEED,
         }
     }
 }
@@ -5,3 +5,21 @@ pub const MAX_SPAWN_MULTIPLIER: f64 = 0.25; // multiplier on game duration for m
 
 pub const NUM_CATS: usize = 4;
 pub const CATS_PER_LEVEL: usize = 1;
+
+// Decoys share the spawn slots with cats from this level on, bonking one fails the game
+pub const DECOY_LEVEL: usize = 1;
+pub const DECOYS_PER_LEVEL: usize = 1;
+pub const MAX_DECOYS: usize = 4;
+pub const DECOY_TRAUMA: f32 = 0.6;
+
+// How long a cat stays up before retreating, shrinking each level
+pub const VISIBLE_WINDOW: Duration = Duration::from_millis(1500);
+pub const MIN_VISIBLE_WINDOW: Duration = Duration::from_millis(600);
+pub const LEVEL_MULTIPLIER: f32 = 0.85;
+// Seconds a retreated cat hides before popping up from another slot
+pub const RETREAT_TIME: std::ops::Range<f32> = 0.2..0.6;
+
+// Bonks this close together count towards a combo, shown from MIN_COMBO
+pub const COMBO_WINDOW: Duration = Duration::from_millis(700);
+pub const MIN_COMBO: usize = 2;
+pub const HIT_DURATION: Duration = Duration::from_millis(250);
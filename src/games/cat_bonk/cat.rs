@@ -1,73 +1,111 @@
 use bevy::prelude::*;
 use rand::prelude::*;
 
+use crate::accessibility::ReducedMotion;
 use crate::audio::sound_effect;
+use crate::easing::Easing;
+use crate::lifetime::DespawnAfter;
+use crate::tween::{PausableTween, ScaleLens, SpriteColorLens, Tween};
 use std::time::Duration;
 
-use crate::games::cat_bonk::{CatBonkAssets, CatBonkState, balance};
+use crate::games::{
+    camera::shake::AddTrauma,
+    cat_bonk::{CAT_SPAWNS, CatBonkAssets, CatBonkState, balance},
+};
 
 #[derive(Debug, Default, Component)]
 pub struct Cat {
+    /// When the cat pops up next, relative to the start of the game
     pub popup_delay: Duration,
+    /// When the cat popped up, while it is up
+    pub shown_at: Option<Duration>,
+    /// Index into [`CAT_SPAWNS`] of the slot the cat is in
+    pub slot: usize,
+    /// Must not be bonked, bonking it fails the game
+    pub decoy: bool,
 }
 
-pub fn cat(
-    assets: &CatBonkAssets,
-    pos: Vec2,
-    texture_atlas_layouts: &mut Assets<TextureAtlasLayout>,
-) -> impl Bundle {
+pub fn cat(assets: &CatBonkAssets, slot: usize, decoy: bool) -> impl Bundle {
     // use bevy random source?
     let mut rng = rand::rng();
     let max = balance::GAME_DURATION.as_secs_f64() * balance::MAX_SPAWN_MULTIPLIER;
     let delay = rng.random_range(0.0..max);
 
-    let layout = TextureAtlasLayout::from_grid(UVec2 { x: 128, y: 60 }, 2, 1, None, None);
-    let texture_atlas_layout = texture_atlas_layouts.add(layout);
+    let (image, layout) = if decoy {
+        (assets.decoy.clone(), assets.decoy_layout.clone())
+    } else {
+        (assets.cat.clone(), assets.cat_layout.clone())
+    };
+    let pos = CAT_SPAWNS[slot];
 
     (
-        Name::new("cat"),
+        Name::new(if decoy { "decoy" } else { "cat" }),
         Transform::from_xyz(pos.x, pos.y, 1.0),
         Visibility::Hidden,
-        Sprite::from_atlas_image(
-            assets.cat.clone(),
-            TextureAtlas {
-                layout: texture_atlas_layout,
-                index: 0,
-            },
-        ),
+        Sprite::from_atlas_image(image, TextureAtlas { layout, index: 0 }),
         Pickable::default(),
         Cat {
             popup_delay: Duration::from_secs_f64(delay),
+            shown_at: None,
+            slot,
+            decoy,
         },
     )
 }
 
-// todo: pop up after random time period
+/// Pop cats up, and retreat them to another free slot once their window has passed
 pub fn update(
     time: Res<Time>,
     state: Res<CatBonkState>,
     mut commands: Commands,
     assets: Res<CatBonkAssets>,
-    cats: Query<(&Cat, &mut Visibility, &mut Sprite)>,
+    mut cats: Query<(&mut Cat, &mut Visibility, &mut Sprite, &mut Transform)>,
 ) {
     let elapsed = time.elapsed() - state.start_time;
+    let mut taken = cats.iter().map(|(cat, ..)| cat.slot).collect::<Vec<_>>();
+    let rng = &mut rand::rng();
 
-    for (cat, mut visibility, mut sprite) in cats {
-        if *visibility == Visibility::Hidden && elapsed > cat.popup_delay {
-            visibility.toggle_visible_hidden();
+    for (mut cat, mut visibility, mut sprite, mut transform) in &mut cats {
+        let Some(shown_at) = cat.shown_at else {
+            if elapsed > cat.popup_delay {
+                *visibility = Visibility::Inherited;
+                cat.shown_at = Some(elapsed);
 
-            let rng = &mut rand::rng();
-            let hit_sound = assets.cat_sounds.choose(rng).unwrap().clone();
-            commands.spawn(sound_effect(hit_sound));
-        }
+                // Decoys stay asleep and quiet
+                if !cat.decoy {
+                    let hit_sound = assets.cat_sounds.choose(rng).unwrap().clone();
+                    commands.spawn(sound_effect(hit_sound));
+                }
+            }
+            continue;
+        };
 
-        if elapsed > cat.popup_delay + Duration::from_secs_f32(0.3)
+        if elapsed > shown_at + Duration::from_secs_f32(0.3)
             && let Some(atlas) = &mut sprite.texture_atlas
         {
             atlas.index = 1;
         }
 
-        //todo: play sound
+        if elapsed > shown_at + state.visible_window {
+            *visibility = Visibility::Hidden;
+            cat.shown_at = None;
+            cat.popup_delay =
+                elapsed + Duration::from_secs_f32(rng.random_range(balance::RETREAT_TIME));
+            if let Some(atlas) = &mut sprite.texture_atlas {
+                atlas.index = 0;
+            }
+
+            // Pop up again from a slot no other cat is in, if there is one
+            let free = (0..CAT_SPAWNS.len())
+                .filter(|slot| !taken.contains(slot))
+                .collect::<Vec<_>>();
+            if let Some(&slot) = free.choose(rng) {
+                taken.retain(|&taken| taken != cat.slot);
+                taken.push(slot);
+                cat.slot = slot;
+                transform.translation = CAT_SPAWNS[slot].extend(transform.translation.z);
+            }
+        }
     }
 }
 
@@ -76,14 +114,61 @@ pub fn on_hit(
     mut commands: Commands,
     assets: Res<CatBonkAssets>,
     mut state: ResMut<CatBonkState>,
+    cats: Query<(&Cat, &Transform)>,
+    time: Res<Time>,
+    reduced_motion: Res<ReducedMotion>,
 ) {
-    commands.entity(click.entity).despawn();
+    let Ok((cat, transform)) = cats.get(click.entity) else {
+        return;
+    };
+
+    // Squash the cat, then fade it out over the second half before despawning it.
+    // It can't be bonked again meanwhile
+    let half = balance::HIT_DURATION / 2;
+    let mut entity = commands.entity(click.entity);
+    entity.remove::<Cat>().insert((
+        Pickable::IGNORE,
+        PausableTween,
+        Tween::new(
+            SpriteColorLens {
+                start: Color::WHITE,
+                end: Color::WHITE.with_alpha(0.0),
+            },
+            half,
+            Easing::QuadIn,
+        )
+        .with_delay(half),
+        DespawnAfter::new(time.elapsed(), balance::HIT_DURATION),
+    ));
+    // Only fade with reduced motion
+    if !reduced_motion.0 {
+        entity.insert(Tween::new(
+            ScaleLens {
+                start: Vec3::new(1.3, 0.6, 1.0) * transform.scale,
+                end: Vec3::new(0.2, 0.1, 1.0) * transform.scale,
+            },
+            balance::HIT_DURATION,
+            Easing::QuadIn,
+        ));
+    }
+
+    if cat.decoy {
+        commands.spawn(sound_effect(assets.decoy_hit_sound.clone()));
+        commands.trigger(AddTrauma::new(balance::DECOY_TRAUMA));
+        state.decoy_hit = true;
+        return;
+    }
 
     let rng = &mut rand::rng();
     let hit_sound = assets.cat_hit_sounds.choose(rng).unwrap().clone();
     commands.spawn(sound_effect(hit_sound));
 
-    //todo: hit effect?
-
+    let elapsed = time.elapsed() - state.start_time;
+    state.combo = if state.combo > 0 && elapsed - state.last_hit < balance::COMBO_WINDOW {
+        state.combo + 1
+    } else {
+        1
+    };
+    state.last_hit = elapsed;
     state.hit_count += 1;
 }
@@ -1,4 +1,10 @@
-use crate::games::cat_bonk::CatBonkAssets;
+use std::time::Duration;
+
+use crate::{
+    easing::Easing,
+    games::cat_bonk::CatBonkAssets,
+    tween::{PausableTween, TranslationLens, Tween, TweenRepeat},
+};
 use bevy::{camera::visibility::RenderLayers, prelude::*};
 
 #[derive(Debug, Default, Component)]
@@ -7,15 +13,22 @@ pub struct Background;
 pub fn level(assets: &CatBonkAssets) -> impl Bundle {
     (
         Name::new("background"),
-        Transform::from_scale(Vec3::splat(1.0)),
+        Transform::from_xyz(-200.0, 0.0, 0.0),
         Visibility::default(),
         Sprite::from_image(assets.background.clone()),
         RenderLayers::default(),
         Background,
+        // Gentle sway side to side
+        PausableTween,
+        Tween::new(
+            TranslationLens {
+                start: Vec3::new(-210.0, 0.0, 0.0),
+                end: Vec3::new(-190.0, 0.0, 0.0),
+            },
+            Duration::from_secs_f32(0.63),
+            Easing::SineInOut,
+        )
+        .with_repeat(TweenRepeat::Forever)
+        .with_yoyo(true),
     )
 }
-
-// todo: replace this with a timed move component or something
-pub fn update(time: Res<Time>, mut background: Single<&mut Transform, With<Background>>) {
-    background.translation.x = f32::sin(time.elapsed_secs() * 5.0) * 10.0 - 200.0;
-}
@@ -3,23 +3,26 @@ use std::time::Duration;
 use rand::seq::index;
 
 use bevy::{
-    app::Propagate,
-    camera::ScalingMode,
-    image::{ImageLoaderSettings, ImageSampler},
-    input::common_conditions::input_just_pressed,
-    prelude::*,
+    app::Propagate, camera::ScalingMode, input::common_conditions::input_just_pressed, prelude::*,
 };
 
 use crate::{
-    asset_tracking::LoadResource,
+    accessibility::ReducedMotion,
+    asset_manifest::{AssetManifest, FromManifest, ManifestError},
     color::color_u32,
+    controls::progress_bar::Pattern,
+    easing::Easing,
     games::{
-        Game, GameControlMethod, GameData, GameInfo, GameResult, NextGame,
+        Game, GameControlMethod, GameData, GameInfo, GameResult, LoadGameResource, NextGame,
         camera::{self, shake::CameraShakeConfig},
     },
+    layout,
+    localization::Localized,
+    menus::MenuAssets,
     screens::Screen,
     theme::widget,
-    timeout::{TimedOut, Timeout, TimeoutBar},
+    timeout::{TimedOut, Timeout, TimeoutBar, WARNING_THRESHOLDS},
+    tween::{PausableTween, Tween, UiScaleLens},
 };
 
 mod balance;
@@ -29,6 +32,23 @@ pub mod weapon;
 
 const GAME: Game = Game::CatBonk;
 
+// hardcoded list of possible cat spawn locations...
+// could have done something smarter here, but theres not too many locations
+const CAT_SPAWNS: [Vec2; 12] = [
+    Vec2::new(-30.0, 120.0),
+    Vec2::new(150.0, 125.0),
+    Vec2::new(350.0, 128.0),
+    Vec2::new(-90.0, 44.0),
+    Vec2::new(98.0, 50.0),
+    Vec2::new(290.0, 56.0),
+    Vec2::new(-32.0, -63.0),
+    Vec2::new(150.0, -55.0),
+    Vec2::new(350.0, -52.0),
+    Vec2::new(-84.0, -136.0),
+    Vec2::new(98.0, -130.0),
+    Vec2::new(290.0, -124.0),
+];
+
 /// Used to track all assets for this game
 #[derive(Resource, Asset, Clone, Reflect)]
 #[reflect(Resource)]
@@ -40,51 +60,41 @@ pub struct CatBonkAssets {
     #[dependency]
     cat: Handle<Image>,
     #[dependency]
+    cat_layout: Handle<TextureAtlasLayout>,
+    #[dependency]
+    decoy: Handle<Image>,
+    #[dependency]
+    decoy_layout: Handle<TextureAtlasLayout>,
+    #[dependency]
     hit_sound: Handle<AudioSource>,
     #[dependency]
     cat_sounds: Vec<Handle<AudioSource>>,
     #[dependency]
     cat_hit_sounds: Vec<Handle<AudioSource>>,
     #[dependency]
+    decoy_hit_sound: Handle<AudioSource>,
+    #[dependency]
     bgm: Handle<AudioSource>,
 }
 
-impl FromWorld for CatBonkAssets {
-    /// Load all assets we want for this game
-    fn from_world(world: &mut World) -> Self {
-        let assets = world.resource::<AssetServer>();
-        Self {
-            background: assets.load_with_settings(
-                "games/cat/background.png",
-                |settings: &mut ImageLoaderSettings| {
-                    settings.sampler = ImageSampler::nearest();
-                },
-            ),
-            weapon: assets.load_with_settings(
-                "games/cat/hammer.png",
-                |settings: &mut ImageLoaderSettings| {
-                    settings.sampler = ImageSampler::nearest();
-                },
-            ),
-            cat: assets.load_with_settings(
-                "games/cat/cat1-sheet.png",
-                |settings: &mut ImageLoaderSettings| {
-                    settings.sampler = ImageSampler::nearest();
-                },
-            ),
-            hit_sound: assets.load("games/cat/hit.ogg"),
-            cat_sounds: vec![
-                assets.load("games/cat/cat1.ogg"),
-                assets.load("games/cat/cat2.ogg"),
-                assets.load("games/cat/cat3.ogg"),
-            ],
-            cat_hit_sounds: vec![
-                assets.load("games/cat/cat_hit1.ogg"),
-                assets.load("games/cat/cat_hit2.ogg"),
-                assets.load("games/cat/cat_hit3.ogg"),
-            ],
-            bgm: assets.load("games/cat/bgm.ogg"),
-        }
+impl FromManifest for CatBonkAssets {
+    const PATH: &'static str = "games/cat/cat_bonk.manifest";
+
+    /// Pick all assets we want for this game out of the manifest
+    fn from_manifest(manifest: &AssetManifest) -> Result<Self, ManifestError> {
+        Ok(Self {
+            background: manifest.image("background")?,
+            weapon: manifest.image("weapon")?,
+            cat: manifest.image("cat")?,
+            cat_layout: manifest.layout("cat")?,
+            decoy: manifest.image("decoy")?,
+            decoy_layout: manifest.layout("decoy")?,
+            hit_sound: manifest.sound("hit")?,
+            cat_sounds: manifest.sounds("cat")?,
+            cat_hit_sounds: manifest.sounds("cat_hit")?,
+            decoy_hit_sound: manifest.sound("decoy_hit")?,
+            bgm: manifest.sound("bgm")?,
+        })
     }
 }
 
@@ -94,6 +104,15 @@ pub struct CatBonkState {
     pub start_time: Duration,
     pub target_count: usize,
     pub hit_count: usize,
+    pub decoy_count: usize,
+    /// A decoy was bonked, which fails the game
+    pub decoy_hit: bool,
+    /// How long a cat stays up before retreating
+    pub visible_window: Duration,
+    /// Cats bonked in quick succession
+    pub combo: usize,
+    /// When the last cat was bonked, relative to `start_time`
+    pub last_hit: Duration,
 }
 
 impl CatBonkState {
@@ -103,44 +122,38 @@ impl CatBonkState {
         self.start_time = start_time;
         self.target_count = balance::NUM_CATS + (level * balance::CATS_PER_LEVEL);
         self.hit_count = 0;
+        self.decoy_count = if level >= balance::DECOY_LEVEL {
+            ((level - balance::DECOY_LEVEL + 1) * balance::DECOYS_PER_LEVEL)
+                .min(balance::MAX_DECOYS)
+        } else {
+            0
+        };
+        self.decoy_hit = false;
+        self.visible_window = balance::VISIBLE_WINDOW
+            .mul_f32(f32::powi(balance::LEVEL_MULTIPLIER, level as i32))
+            .max(balance::MIN_VISIBLE_WINDOW);
+        self.combo = 0;
+        self.last_hit = Duration::ZERO;
     }
 }
 
+/// Shows the combo once there is one
+#[derive(Debug, Component)]
+struct ComboLabel;
+
 pub fn spawn(
     mut commands: Commands,
     gamedata: Res<GameData>,
     assets: Res<CatBonkAssets>,
+    menu_assets: Res<MenuAssets>,
     mut state: ResMut<CatBonkState>,
-    mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
     time: Res<Time>,
 ) {
     state.reset(time.elapsed(), gamedata.level);
 
-    // hardcoded list of possible cat spawn locations...
-    // could have done something smarter here, but theres not too many locations
-    let cat_spawns = [
-        Vec2 { x: -30.0, y: 120.0 },
-        Vec2 { x: 150.0, y: 125.0 },
-        Vec2 { x: 350.0, y: 128.0 },
-        Vec2 { x: -90.0, y: 44.0 },
-        Vec2 { x: 98.0, y: 50.0 },
-        Vec2 { x: 290.0, y: 56.0 },
-        Vec2 { x: -32.0, y: -63.0 },
-        Vec2 { x: 150.0, y: -55.0 },
-        Vec2 { x: 350.0, y: -52.0 },
-        Vec2 {
-            x: -84.0,
-            y: -136.0,
-        },
-        Vec2 { x: 98.0, y: -130.0 },
-        Vec2 {
-            x: 290.0,
-            y: -124.0,
-        },
-    ];
-
     let mut rng = rand::rng();
-    let indices = index::sample(&mut rng, cat_spawns.len(), state.target_count);
+    let spawn_count = (state.target_count + state.decoy_count).min(CAT_SPAWNS.len());
+    let indices = index::sample(&mut rng, CAT_SPAWNS.len(), spawn_count);
 
     commands.spawn((
         DespawnOnExit(GAME),             // When exiting this game despawn this entity
@@ -167,23 +180,32 @@ pub fn spawn(
             DespawnOnExit(GAME), // When exiting this game despawn this entity
             DespawnOnExit(Screen::Gameplay), // When exiting the top level game despawn this entity
             Timeout::new(balance::GAME_DURATION),
-            children![TimeoutBar::from_foreground_color(color_u32(
-                get_info().color
-            ))],
+            children![
+                (
+                    layout::top_right(),
+                    children![(
+                        widget::label_with_shadow(
+                            Localized::verbatim(""),
+                            menu_assets.font.clone()
+                        ),
+                        ComboLabel,
+                    )],
+                ),
+                TimeoutBar::from_foreground_color(color_u32(get_info().color))
+                    .with_pattern(get_info().pattern)
+                    .with_thresholds(WARNING_THRESHOLDS)
+            ],
         ))
         .observe(timed_out);
 
     let level = commands
         .spawn(level::level(&assets))
         .with_children(|parent| {
-            // spawn cats at random locations
-            for spawn_index in indices {
+            // spawn cats at random locations, and decoys in the slots left over
+            for (i, spawn_index) in indices.into_iter().enumerate() {
+                let decoy = i >= state.target_count;
                 parent
-                    .spawn(cat::cat(
-                        &assets,
-                        cat_spawns[spawn_index],
-                        &mut texture_atlas_layouts,
-                    ))
+                    .spawn(cat::cat(&assets, spawn_index, decoy))
                     .observe(cat::on_hit);
             }
         })
@@ -206,13 +228,13 @@ pub fn spawn(
 }
 
 pub(super) fn plugin(app: &mut App) {
-    app.load_resource::<CatBonkAssets>();
+    app.load_game_manifest::<CatBonkAssets>(GAME);
     app.add_systems(OnEnter(GAME), spawn);
     app.add_systems(
         Update,
         (
             update,
-            level::update,
+            update_combo,
             weapon::update,
             cat::update,
             weapon::update_weapon_hit.run_if(input_just_pressed(MouseButton::Left)),
@@ -226,8 +248,9 @@ pub const fn get_info() -> GameInfo {
     GameInfo {
         kind: GAME,
         controls: GameControlMethod::Mouse,
-        hint: "Bonk",
+        hint: "hint.bonk",
         color: 0x279CD8FF,
+        pattern: Pattern::Stripes,
     }
 }
 
@@ -238,8 +261,49 @@ fn timed_out(_event: On<TimedOut>, mut tx: MessageWriter<NextGame>) {
 
 /// Just a simple system that transitions us to the next game after some time
 pub fn update(state: Res<CatBonkState>, mut tx: MessageWriter<NextGame>) {
-    if state.hit_count >= state.target_count {
+    if state.decoy_hit {
+        tx.write(NextGame::failed_because("cat_bonk.decoy_hit"));
+        info!("decoy hit - next game");
+    } else if state.hit_count >= state.target_count {
         tx.write(NextGame::from_result(GameResult::Passsed));
         info!("all targets hit - next game");
     }
 }
+
+fn update_combo(
+    mut commands: Commands,
+    mut state: ResMut<CatBonkState>,
+    mut query: Query<(Entity, &mut Localized), With<ComboLabel>>,
+    time: Res<Time>,
+    reduced_motion: Res<ReducedMotion>,
+) {
+    // The combo breaks when no cat is bonked in time
+    if state.combo > 0 && time.elapsed() - state.start_time - state.last_hit > balance::COMBO_WINDOW
+    {
+        state.combo = 0;
+    }
+    if !state.is_changed() {
+        return;
+    }
+    let text = if state.combo >= balance::MIN_COMBO {
+        Localized::new("cat_bonk.combo").with_arg("combo", state.combo)
+    } else {
+        Localized::verbatim("")
+    };
+    for (entity, mut label) in &mut query {
+        if label.set_if_neq(text.clone()) && state.combo >= balance::MIN_COMBO && !reduced_motion.0
+        {
+            commands.entity(entity).insert((
+                PausableTween,
+                Tween::new(
+                    UiScaleLens {
+                        start: Vec2::splat(1.5),
+                        end: Vec2::ONE,
+                    },
+                    Duration::from_millis(200),
+                    Easing::BackOut,
+                ),
+            ));
+        }
+    }
+}
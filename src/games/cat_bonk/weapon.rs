@@ -2,7 +2,7 @@ use bevy::prelude::*;
 
 use crate::audio::sound_effect;
 use crate::float::Floats;
-use crate::games::{camera::shake::CameraShakeState, cat_bonk::CatBonkAssets};
+use crate::games::{camera::shake::AddTrauma, cat_bonk::CatBonkAssets};
 
 #[derive(Debug, Default, Component)]
 pub struct Weapon;
@@ -49,13 +49,7 @@ pub fn update(
     weapon.rotation = Quat::from_rotation_z(angle.to_radians());
 }
 
-pub fn update_weapon_hit(
-    mut commands: Commands,
-    mut camera_shake: Single<&mut CameraShakeState>,
-    assets: Res<CatBonkAssets>,
-) {
-    camera_shake.trauma += 0.3;
-    camera_shake.trauma = camera_shake.trauma.clamp(0.0, 1.0);
-
+pub fn update_weapon_hit(mut commands: Commands, assets: Res<CatBonkAssets>) {
+    commands.trigger(AddTrauma::kick(0.3, Vec2::NEG_Y));
     commands.spawn(sound_effect(assets.hit_sound.clone()));
 }
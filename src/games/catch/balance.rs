@@ -1,7 +1,26 @@
 use std::time::Duration;
 
+use bevy::math::Vec2;
+
 pub const GAME_DURATION: Duration = Duration::from_secs(5);
+/// Height of the world shown by the game camera
+pub const VIEW_HEIGHT: f32 = 1080.0;
+/// Half size of the area balls drop in and the glove is kept in
+pub const PLAY_AREA: Vec2 = Vec2::new(800.0, 400.0);
+
 pub const DROP_FREQ: Duration = Duration::from_millis(500);
+pub const BALL_LIFETIME: Duration = Duration::from_millis(1500);
+/// Scales both the drop frequency and ball lifetime for each level
+pub const LEVEL_MULTIPLIER: f32 = 0.9;
+
+pub const REQUIRED_CATCHES: u32 = 3;
+pub const CATCHES_PER_LEVEL: u32 = 1;
+pub const MAX_REQUIRED_CATCHES: u32 = 6;
+
 pub const GLOVE_SPEED: f32 = 1200.0;
 pub const GLOVE_RADIUS: f32 = 250.0;
+pub const GLOVE_SCALE: f32 = 0.5;
+/// Scale the glove pops up to when catching a ball
+pub const GLOVE_CATCH_SCALE: f32 = 0.6;
 pub const BALL_RADIUS: f32 = 250.0;
+pub const MISS_TRAUMA: f32 = 0.2;
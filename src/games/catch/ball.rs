@@ -1,21 +1,26 @@
 use std::time::Duration;
 
 use bevy::prelude::*;
+use rand::Rng;
 
 use crate::{
-    games::catch::{CatchAssets, CatchState, balance},
-    lifetime::DespawnAfter,
-    random::Random2dPosition,
+    audio::sound_effect,
+    games::{
+        camera::shake::AddTrauma,
+        catch::{CatchAssets, CatchState, balance},
+    },
 };
 
 #[derive(Debug, Default, Clone, Copy, Component)]
 pub struct Ball {
     pub radius: f32,
+    /// When the ball is missed if it hasn't been caught
+    pub expiry: Duration,
 }
 
 impl Ball {
-    pub fn new(radius: f32) -> Self {
-        Self { radius }
+    pub fn new(radius: f32, expiry: Duration) -> Self {
+        Self { radius, expiry }
     }
 }
 
@@ -26,7 +31,6 @@ pub fn spawn(
     assets: Res<CatchAssets>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
-    random: Res<Random2dPosition>,
 ) {
     if (time.elapsed() - state.last_release) > state.release_freq {
         state.last_release = time.elapsed();
@@ -36,9 +40,7 @@ pub fn spawn(
                     &assets,
                     &mut meshes,
                     &mut materials,
-                    &random,
-                    &time,
-                    state.release_freq,
+                    time.elapsed() + state.ball_lifetime,
                 ))
                 .id();
             commands.entity(root).add_child(ball_entity);
@@ -46,22 +48,46 @@ pub fn spawn(
     }
 }
 
+/// Counts balls that were not caught in time as dropped
+pub fn update_missed(
+    mut commands: Commands,
+    mut state: ResMut<CatchState>,
+    assets: Res<CatchAssets>,
+    query: Query<(Entity, &Ball)>,
+    time: Res<Time>,
+) {
+    for (entity, ball) in query.iter() {
+        if time.elapsed() > ball.expiry {
+            state.dropped += 1;
+            commands.entity(entity).despawn();
+            commands.spawn(sound_effect(assets.miss_sound.clone()));
+            commands.trigger(AddTrauma::new(balance::MISS_TRAUMA));
+        }
+    }
+}
+
 pub fn ball(
     assets: &CatchAssets,
     meshes: &mut ResMut<Assets<Mesh>>,
     materials: &mut ResMut<Assets<ColorMaterial>>,
-    random: &Res<Random2dPosition>,
-    time: &Res<Time>,
-    lifespan: Duration,
+    expiry: Duration,
 ) -> impl Bundle {
     (
         Name::new("ball"),
-        Ball::new(balance::BALL_RADIUS),
+        Ball::new(balance::BALL_RADIUS, expiry),
         Mesh2d(meshes.add(Circle::new(balance::BALL_RADIUS))),
         MeshMaterial2d(materials.add(Color::linear_rgb(0.5, 0.5, 0.1))),
         Sprite::from_image(assets.ball.clone()),
-        Transform::from_translation(random.next(balance::BALL_RADIUS).extend(0.0))
+        Transform::from_translation(random_position().extend(0.0))
             .with_scale(Vec2::splat(0.25).extend(1.0)),
-        DespawnAfter::new(time.elapsed(), lifespan),
+    )
+}
+
+/// A random position inside the play area
+fn random_position() -> Vec2 {
+    let mut rng = rand::rng();
+    Vec2::new(
+        rng.random_range(-balance::PLAY_AREA.x..balance::PLAY_AREA.x),
+        rng.random_range(-balance::PLAY_AREA.y..balance::PLAY_AREA.y),
     )
 }
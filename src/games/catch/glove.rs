@@ -2,7 +2,7 @@ use bevy::prelude::*;
 
 use crate::{
     games::catch::{CatchAssets, balance},
-    movement::{ScreenWrap, TopDownMovementController},
+    movement::TopDownMovementController,
 };
 
 #[derive(Debug, Default, Clone, Copy, Component)]
@@ -28,11 +28,21 @@ pub fn glove(
         Mesh2d(meshes.add(Circle::new(balance::GLOVE_RADIUS))),
         MeshMaterial2d(materials.add(Color::linear_rgb(0.2, 0.5, 0.3))),
         Sprite::from_image(assets.glove.clone()),
-        Transform::from_scale(Vec2::splat(0.5).extend(1.0)), // TODO: Random start position??
+        Transform::from_scale(Vec2::splat(balance::GLOVE_SCALE).extend(1.0)), // TODO: Random start position??
         TopDownMovementController {
             max_speed: Vec2::splat(max_speed),
             ..default()
         },
-        ScreenWrap,
     )
 }
+
+/// Keep the glove inside the play area
+pub fn confine(mut query: Query<&mut Transform, With<Glove>>) {
+    for mut transform in query.iter_mut() {
+        let position = transform
+            .translation
+            .xy()
+            .clamp(-balance::PLAY_AREA, balance::PLAY_AREA);
+        transform.translation = position.extend(transform.translation.z);
+    }
+}
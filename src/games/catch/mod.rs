@@ -1,19 +1,29 @@
 use std::time::Duration;
 
-use bevy::prelude::*;
+use bevy::{app::Propagate, camera::ScalingMode, prelude::*};
 
 use crate::{
     AppSystems, PausableSystems,
-    asset_tracking::LoadResource,
+    accessibility::ReducedMotion,
+    asset_manifest::{AssetManifest, FromManifest, ManifestError},
+    audio::sound_effect,
+    color::color_u32,
+    controls::progress_bar::Pattern,
+    easing::Easing,
     games::{
-        Game, GameControlMethod, GameInfo, GameResult, NextGame,
+        Game, GameControlMethod, GameData, GameInfo, GameResult, LoadGameResource, NextGame,
+        camera::{self, shake::CameraShakeConfig},
         catch::{
             ball::Ball,
             glove::{Glove, glove},
         },
     },
+    localization::Localized,
+    menus::MenuAssets,
     screens::Screen,
-    timeout::Timeout,
+    theme::widget,
+    timeout::{TimedOut, Timeout, TimeoutBar, WARNING_THRESHOLDS},
+    tween::{PausableTween, ScaleLens, Tween},
 };
 
 mod balance;
@@ -24,7 +34,7 @@ const GAME: Game = Game::Catch;
 
 pub(super) fn plugin(app: &mut App) {
     // Register our assets to be loaded when the application is loading
-    app.load_resource::<CatchAssets>();
+    app.load_game_manifest::<CatchAssets>(GAME);
 
     // Register our spawn system to be triggered when this game is selected
     app.add_systems(OnEnter(GAME), spawn);
@@ -32,7 +42,14 @@ pub(super) fn plugin(app: &mut App) {
     // Register all systems that are to be run when this game is active
     app.add_systems(
         Update,
-        (update, ball::spawn, update_catch)
+        (
+            update,
+            ball::spawn,
+            ball::update_missed,
+            update_catch,
+            glove::confine,
+            update_count,
+        )
             .in_set(AppSystems::Update)
             .in_set(PausableSystems)
             .run_if(in_state(GAME)),
@@ -46,8 +63,9 @@ pub const fn get_info() -> GameInfo {
     GameInfo {
         kind: GAME,
         controls: GameControlMethod::Wasd,
-        hint: "Catch",
-        color: 0xFFFFFFFF,
+        hint: "hint.catch",
+        color: 0xFFAA33FF,
+        pattern: Pattern::Solid,
     }
 }
 
@@ -55,10 +73,12 @@ pub const fn get_info() -> GameInfo {
 #[derive(Debug, Default, Clone, Copy, Resource)]
 pub struct CatchState {
     pub start_time: Duration,
-    pub run_time: Duration,
     pub caught: u32,
     pub dropped: u32,
+    /// Balls to catch before the timeout to pass
+    pub required: u32,
     pub release_freq: Duration,
+    pub ball_lifetime: Duration,
     pub last_release: Duration,
     pub root: Option<Entity>,
 }
@@ -66,12 +86,15 @@ pub struct CatchState {
 impl CatchState {
     /// Called when starting this game to make sure the data is reset
     /// Assuming that is what we want.
-    pub fn reset(&mut self, start_time: Duration, root: Entity) {
+    pub fn reset(&mut self, start_time: Duration, root: Entity, level: usize) {
+        let level_multiplier = f32::powi(balance::LEVEL_MULTIPLIER, level as i32);
         self.start_time = start_time;
-        self.run_time = balance::GAME_DURATION;
         self.caught = 0;
         self.dropped = 0;
-        self.release_freq = balance::DROP_FREQ; // TODO: Scale based on rounds/time
+        self.required = (balance::REQUIRED_CATCHES + level as u32 * balance::CATCHES_PER_LEVEL)
+            .min(balance::MAX_REQUIRED_CATCHES);
+        self.release_freq = balance::DROP_FREQ.mul_f32(level_multiplier);
+        self.ball_lifetime = balance::BALL_LIFETIME.mul_f32(level_multiplier);
         self.last_release = start_time;
         self.root = Some(root);
     }
@@ -85,28 +108,63 @@ pub struct CatchAssets {
     glove: Handle<Image>,
     #[dependency]
     ball: Handle<Image>,
+    #[dependency]
+    catch_sound: Handle<AudioSource>,
+    #[dependency]
+    miss_sound: Handle<AudioSource>,
+    #[dependency]
+    bgm: Handle<AudioSource>,
 }
 
-impl FromWorld for CatchAssets {
-    /// Load all assets we want for this game
-    fn from_world(world: &mut World) -> Self {
-        let assets = world.resource::<AssetServer>();
-        Self {
-            glove: assets.load("games/catch/glove.png"),
-            ball: assets.load("games/catch/ball.png"),
-        }
+impl FromManifest for CatchAssets {
+    const PATH: &'static str = "games/catch/catch.manifest";
+
+    /// Pick all assets we want for this game out of the manifest
+    fn from_manifest(manifest: &AssetManifest) -> Result<Self, ManifestError> {
+        Ok(Self {
+            glove: manifest.image("glove")?,
+            ball: manifest.image("ball")?,
+            catch_sound: manifest.sound("catch")?,
+            miss_sound: manifest.sound("miss")?,
+            bgm: manifest.sound("bgm")?,
+        })
     }
 }
 
-/// A system to spawn the example level
+/// Shows how many balls have been caught out of those required
+#[derive(Debug, Component)]
+struct CatchCount;
+
+/// A system to spawn the catch level
 pub fn spawn(
     mut commands: Commands,
     assets: Res<CatchAssets>,
+    menu_assets: Res<MenuAssets>,
+    gamedata: Res<GameData>,
     mut state: ResMut<CatchState>,
     time: Res<Time>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
+    commands.spawn((
+        DespawnOnExit(GAME),             // When exiting this game despawn this entity
+        DespawnOnExit(Screen::Gameplay), // When exiting the top level game despawn this entity
+        Camera2d,
+        CameraShakeConfig::default(),
+        Camera {
+            order: -1,
+            clear_color: ClearColorConfig::None,
+            ..default()
+        },
+        Projection::from(OrthographicProjection {
+            scaling_mode: ScalingMode::FixedVertical {
+                viewport_height: balance::VIEW_HEIGHT,
+            },
+            ..OrthographicProjection::default_2d()
+        }),
+        camera::RENDERLAYER_GAME,
+    ));
+
     let root = commands
         .spawn((
             Name::new("catch_level"),
@@ -114,7 +172,7 @@ pub fn spawn(
             Visibility::default(),
             DespawnOnExit(GAME), // When exiting this game despawn this entity
             DespawnOnExit(Screen::Gameplay), // When exiting the top level game despawn this entity
-            Timeout::default(),
+            Propagate(camera::RENDERLAYER_GAME),
             children![glove(
                 balance::GLOVE_SPEED,
                 &assets,
@@ -123,28 +181,72 @@ pub fn spawn(
             ),],
         ))
         .id();
-    state.reset(time.elapsed(), root);
+    state.reset(time.elapsed(), root, gamedata.level);
+
+    commands
+        .spawn((
+            widget::ui_root("catch_ui"),
+            DespawnOnExit(GAME), // When exiting this game despawn this entity
+            DespawnOnExit(Screen::Gameplay), // When exiting the top level game despawn this entity
+            Timeout::new(balance::GAME_DURATION),
+            AudioPlayer(assets.bgm.clone()),
+            children![
+                (
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: px(20),
+                        ..default()
+                    },
+                    children![(
+                        widget::label_with_shadow(count_text(&state), menu_assets.font.clone()),
+                        CatchCount,
+                    )],
+                ),
+                TimeoutBar::from_foreground_color(color_u32(get_info().color))
+                    .with_pattern(get_info().pattern)
+                    .with_thresholds(WARNING_THRESHOLDS)
+            ],
+        ))
+        .observe(timed_out);
+}
+
+fn count_text(state: &CatchState) -> Localized {
+    Localized::new("catch.count")
+        .with_arg("caught", state.caught)
+        .with_arg("required", state.required)
+}
+
+fn timed_out(_event: On<TimedOut>, mut tx: MessageWriter<NextGame>) {
+    tx.write(NextGame::from_result(GameResult::Failed));
+    info!("timeout - next game");
 }
 
-/// Just a simple system that transitions us to the next game after some time
-pub fn update(state: Res<CatchState>, time: Res<Time>, mut tx: MessageWriter<NextGame>) {
-    if time.elapsed() - state.start_time > state.run_time {
-        tx.write(NextGame::from_result(if state.caught > 3 {
-            GameResult::Passsed
-        } else {
-            GameResult::Failed
-        }));
-        info!("Next game");
+/// Pass as soon as enough balls have been caught
+pub fn update(state: Res<CatchState>, mut tx: MessageWriter<NextGame>) {
+    if state.caught >= state.required {
+        tx.write(NextGame::from_result(GameResult::Passsed));
+        info!("caught enough - next game");
+    }
+}
+
+fn update_count(state: Res<CatchState>, mut query: Query<&mut Localized, With<CatchCount>>) {
+    if !state.is_changed() {
+        return;
+    }
+    for mut text in query.iter_mut() {
+        text.set_if_neq(count_text(&state));
     }
 }
 
 pub fn update_catch(
     mut commands: Commands,
     mut state: ResMut<CatchState>,
+    assets: Res<CatchAssets>,
     ball_query: Query<(Entity, &Transform, &Ball)>,
-    glove_query: Query<(&Transform, &Glove)>,
+    glove_query: Query<(Entity, &Transform, &Glove)>,
+    reduced_motion: Res<ReducedMotion>,
 ) {
-    for (glove_transform, glove) in glove_query.iter() {
+    for (glove_entity, glove_transform, glove) in glove_query.iter() {
         for (ball_entity, ball_transform, ball) in ball_query.iter() {
             let distance = (glove_transform.translation - ball_transform.translation).length();
 
@@ -153,6 +255,20 @@ pub fn update_catch(
             {
                 state.caught += 1;
                 commands.entity(ball_entity).despawn();
+                commands.spawn(sound_effect(assets.catch_sound.clone()));
+                if !reduced_motion.0 {
+                    commands.entity(glove_entity).insert((
+                        PausableTween,
+                        Tween::new(
+                            ScaleLens {
+                                start: Vec2::splat(balance::GLOVE_CATCH_SCALE).extend(1.0),
+                                end: Vec2::splat(balance::GLOVE_SCALE).extend(1.0),
+                            },
+                            Duration::from_millis(200),
+                            Easing::BackOut,
+                        ),
+                    ));
+                }
                 info!("Caught ball!",);
             }
         }
@@ -0,0 +1,28 @@
+use std::time::Duration;
+
+use bevy::math::Vec2;
+
+pub const GAME_DURATION: Duration = Duration::from_secs(5);
+/// Height of the world shown by the game camera
+pub const VIEW_HEIGHT: f32 = 360.0;
+/// Half size of the pond, the duckling is kept inside it
+pub const POND: Vec2 = Vec2::new(280.0, 150.0);
+/// Width of the shore on the right side of the pond the duckling swims to
+pub const SHORE_WIDTH: f32 = 50.0;
+
+pub const DUCKLING_SPEED: f32 = 150.0;
+pub const DUCKLING_SIZE: f32 = 32.0;
+// Distance from the center of the duckling that counts as touching something
+pub const DUCKLING_RADIUS: f32 = 12.0;
+// Seconds between step sounds while swimming
+pub const STEP_INTERVAL: f32 = 0.25;
+
+pub const LOGS: usize = 2;
+pub const LOGS_PER_LEVEL: usize = 1;
+pub const MAX_LOGS: usize = 5;
+pub const LOG_SIZE: Vec2 = Vec2::new(24.0, 80.0);
+pub const LOG_SPEED: f32 = 70.0;
+// Scales the speed of the logs for each level
+pub const LEVEL_MULTIPLIER: f32 = 1.15;
+
+pub const BUMP_TRAUMA: f32 = 0.4;
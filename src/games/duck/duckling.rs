@@ -0,0 +1,70 @@
+use bevy::prelude::*;
+use rand::prelude::*;
+
+use crate::{
+    audio::sound_effect,
+    games::duck::{DuckAssets, balance},
+    movement::TopDownMovementController,
+};
+
+/// The player, swimming across the pond
+#[derive(Component)]
+pub struct Duckling {
+    /// Counts down to the next step sound while swimming
+    step_timer: Timer,
+}
+
+pub fn duckling(assets: &DuckAssets, pos: Vec2) -> impl Bundle {
+    (
+        Name::new("duckling"),
+        Transform::from_translation(pos.extend(10.0)),
+        Visibility::default(),
+        Sprite {
+            image: assets.duckling.clone(),
+            custom_size: Some(Vec2::splat(balance::DUCKLING_SIZE)),
+            ..default()
+        },
+        TopDownMovementController {
+            max_speed: Vec2::splat(balance::DUCKLING_SPEED),
+            ..default()
+        },
+        Duckling {
+            step_timer: Timer::from_seconds(balance::STEP_INTERVAL, TimerMode::Repeating),
+        },
+    )
+}
+
+/// Face the way the duckling swims and play a step sound every few strokes
+pub fn paddle(
+    mut commands: Commands,
+    time: Res<Time>,
+    assets: Res<DuckAssets>,
+    mut query: Query<(&TopDownMovementController, &mut Duckling, &mut Sprite)>,
+) {
+    for (controller, mut duckling, mut sprite) in &mut query {
+        if controller.intent.x != 0.0 {
+            sprite.flip_x = controller.intent.x < 0.0;
+        }
+
+        if controller.intent == Vec2::ZERO {
+            duckling.step_timer.reset();
+            continue;
+        }
+
+        duckling.step_timer.tick(time.delta());
+        if duckling.step_timer.just_finished()
+            && let Some(step) = assets.steps.choose(&mut rand::rng())
+        {
+            commands.spawn(sound_effect(step.clone()));
+        }
+    }
+}
+
+/// Keep the duckling in the pond
+pub fn confine(mut query: Query<&mut Transform, With<Duckling>>) {
+    let max = balance::POND - balance::DUCKLING_SIZE / 2.0;
+    for mut transform in &mut query {
+        let position = transform.translation.xy().clamp(-max, max);
+        transform.translation = position.extend(transform.translation.z);
+    }
+}
@@ -0,0 +1,38 @@
+use bevy::prelude::*;
+
+use crate::games::duck::balance;
+
+/// A floating log drifting up and down the pond, the duckling must not touch it
+#[derive(Component)]
+pub struct Log {
+    /// Vertical speed, flipped at the edges of the pond
+    velocity: f32,
+}
+
+pub fn log(pos: Vec2, velocity: f32) -> impl Bundle {
+    (
+        Name::new("log"),
+        Transform::from_translation(pos.extend(5.0)),
+        Visibility::default(),
+        Sprite::from_color(Color::srgb(0.45, 0.3, 0.15), balance::LOG_SIZE),
+        Log { velocity },
+    )
+}
+
+pub fn drift(time: Res<Time>, mut query: Query<(&mut Log, &mut Transform)>) {
+    let max = balance::POND.y - balance::LOG_SIZE.y / 2.0;
+    for (mut log, mut transform) in &mut query {
+        transform.translation.y += log.velocity * time.delta_secs();
+        if transform.translation.y.abs() > max {
+            transform.translation.y = transform.translation.y.clamp(-max, max);
+            log.velocity = -log.velocity;
+        }
+    }
+}
+
+/// Whether a circle of `radius` at `point` touches the log at `center`
+pub fn touches(center: Vec2, point: Vec2, radius: f32) -> bool {
+    let half_size = balance::LOG_SIZE / 2.0;
+    let closest = point.clamp(center - half_size, center + half_size);
+    closest.distance(point) < radius
+}
@@ -0,0 +1,241 @@
+use std::time::Duration;
+
+use bevy::{app::Propagate, camera::ScalingMode, prelude::*};
+use rand::Rng;
+
+use crate::{
+    AppSystems, PausableSystems,
+    asset_manifest::{AssetManifest, FromManifest, ManifestError},
+    color::color_u32,
+    controls::progress_bar::Pattern,
+    games::{
+        Game, GameControlMethod, GameData, GameInfo, GameResult, LoadGameResource, NextGame,
+        camera::{
+            self,
+            shake::{AddTrauma, CameraShakeConfig},
+        },
+        duck::{duckling::Duckling, log::Log},
+    },
+    screens::Screen,
+    theme::widget,
+    timeout::{TimedOut, Timeout, TimeoutBar, WARNING_THRESHOLDS},
+};
+
+mod balance;
+mod duckling;
+mod log;
+
+const GAME: Game = Game::Duck;
+
+pub(super) fn plugin(app: &mut App) {
+    // Register our assets to be loaded when the application is loading
+    app.load_game_manifest::<DuckAssets>(GAME);
+
+    // Register our spawn system to be triggered when this game is selected
+    app.add_systems(OnEnter(GAME), spawn);
+
+    // Register all systems that are to be run when this game is active
+    app.add_systems(
+        Update,
+        (
+            duckling::paddle,
+            duckling::confine,
+            log::drift,
+            (update_bump, update).chain(),
+        )
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems)
+            .run_if(in_state(GAME)),
+    );
+
+    // Register a basic data structure that we can use to track data for this game
+    app.init_resource::<DuckState>();
+}
+
+pub const fn get_info() -> GameInfo {
+    GameInfo {
+        kind: GAME,
+        controls: GameControlMethod::Wasd,
+        hint: "hint.cross",
+        color: 0x44BBEEFF,
+        pattern: Pattern::Waves,
+    }
+}
+
+/// All data representing the current state of this game
+#[derive(Debug, Default, Clone, Copy, Resource)]
+pub struct DuckState {
+    pub start_time: Duration,
+    /// The duckling reached the shore or bumped into a log, so the result is known
+    pub done: bool,
+}
+
+impl DuckState {
+    /// Called when starting this game to make sure the data is reset
+    /// Assuming that is what we want.
+    pub fn reset(&mut self, start_time: Duration) {
+        self.start_time = start_time;
+        self.done = false;
+    }
+}
+
+/// Used to track all assets for this game
+#[derive(Resource, Asset, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct DuckAssets {
+    #[dependency]
+    duckling: Handle<Image>,
+    #[dependency]
+    steps: Vec<Handle<AudioSource>>,
+    #[dependency]
+    bgm: Handle<AudioSource>,
+}
+
+impl FromManifest for DuckAssets {
+    const PATH: &'static str = "games/duck/duck.manifest";
+
+    /// Pick all assets we want for this game out of the manifest
+    fn from_manifest(manifest: &AssetManifest) -> Result<Self, ManifestError> {
+        Ok(Self {
+            duckling: manifest.image("duckling")?,
+            steps: manifest.sounds("steps")?,
+            bgm: manifest.sound("bgm")?,
+        })
+    }
+}
+
+/// A system to spawn the pond
+pub fn spawn(
+    mut commands: Commands,
+    assets: Res<DuckAssets>,
+    gamedata: Res<GameData>,
+    mut state: ResMut<DuckState>,
+    time: Res<Time>,
+) {
+    state.reset(time.elapsed());
+
+    commands.spawn((
+        DespawnOnExit(GAME),             // When exiting this game despawn this entity
+        DespawnOnExit(Screen::Gameplay), // When exiting the top level game despawn this entity
+        Camera2d,
+        CameraShakeConfig::default(),
+        Camera {
+            order: -1,
+            clear_color: ClearColorConfig::None,
+            ..default()
+        },
+        Projection::from(OrthographicProjection {
+            scaling_mode: ScalingMode::FixedVertical {
+                viewport_height: balance::VIEW_HEIGHT,
+            },
+            ..OrthographicProjection::default_2d()
+        }),
+        camera::RENDERLAYER_GAME,
+    ));
+
+    let root = commands
+        .spawn((
+            Name::new("pond"),
+            Transform::default(),
+            Visibility::default(),
+            DespawnOnExit(GAME), // When exiting this game despawn this entity
+            DespawnOnExit(Screen::Gameplay), // When exiting the top level game despawn this entity
+            Propagate(camera::RENDERLAYER_GAME),
+            AudioPlayer(assets.bgm.clone()),
+            Sprite::from_color(Color::srgb(0.2, 0.45, 0.75), balance::POND * 2.0),
+            children![
+                (
+                    Name::new("shore"),
+                    Transform::from_xyz(balance::POND.x - balance::SHORE_WIDTH / 2.0, 0.0, 1.0),
+                    Sprite::from_color(
+                        Color::srgb(0.35, 0.65, 0.3),
+                        Vec2::new(balance::SHORE_WIDTH, balance::POND.y * 2.0),
+                    ),
+                ),
+                duckling::duckling(&assets, Vec2::new(-balance::POND.x + 30.0, 0.0)),
+            ],
+        ))
+        .id();
+
+    // Logs are spread between the start and the shore, drifting faster each level
+    let mut rng = rand::rng();
+    let level_multiplier = f32::powi(balance::LEVEL_MULTIPLIER, gamedata.level as i32);
+    let logs = (balance::LOGS + gamedata.level * balance::LOGS_PER_LEVEL).min(balance::MAX_LOGS);
+    let first = -balance::POND.x + 100.0;
+    let last = balance::POND.x - balance::SHORE_WIDTH - 40.0;
+    let max_y = balance::POND.y - balance::LOG_SIZE.y / 2.0;
+    commands.entity(root).with_children(|parent| {
+        for i in 0..logs {
+            let x = first + (last - first) * (i as f32 + 0.5) / logs as f32;
+            let y = rng.random_range(-max_y..max_y);
+            let direction = if rng.random_bool(0.5) { 1.0 } else { -1.0 };
+            parent.spawn(log::log(
+                Vec2::new(x, y),
+                direction * balance::LOG_SPEED * level_multiplier,
+            ));
+        }
+    });
+
+    commands
+        .spawn((
+            widget::ui_root("duck_ui"),
+            DespawnOnExit(GAME), // When exiting this game despawn this entity
+            DespawnOnExit(Screen::Gameplay), // When exiting the top level game despawn this entity
+            Timeout::new(balance::GAME_DURATION),
+            children![
+                TimeoutBar::from_foreground_color(color_u32(get_info().color))
+                    .with_pattern(get_info().pattern)
+                    .with_thresholds(WARNING_THRESHOLDS)
+            ],
+        ))
+        .observe(timed_out);
+}
+
+fn timed_out(_event: On<TimedOut>, mut tx: MessageWriter<NextGame>, state: Res<DuckState>) {
+    if !state.done {
+        tx.write(NextGame::failed_because("duck.too_slow"));
+        info!("timeout - next game");
+    }
+}
+
+/// Fail as soon as the duckling touches a log
+fn update_bump(
+    mut commands: Commands,
+    mut state: ResMut<DuckState>,
+    mut tx: MessageWriter<NextGame>,
+    duckling: Single<&Transform, With<Duckling>>,
+    logs: Query<&Transform, With<Log>>,
+) {
+    if state.done {
+        return;
+    }
+    let position = duckling.translation.xy();
+    if logs.iter().any(|transform| {
+        log::touches(
+            transform.translation.xy(),
+            position,
+            balance::DUCKLING_RADIUS,
+        )
+    }) {
+        state.done = true;
+        commands.trigger(AddTrauma::new(balance::BUMP_TRAUMA));
+        tx.write(NextGame::failed_because("duck.bumped"));
+        info!("bumped into a log - next game");
+    }
+}
+
+/// Pass as soon as the duckling reaches the shore
+fn update(
+    mut state: ResMut<DuckState>,
+    mut tx: MessageWriter<NextGame>,
+    duckling: Single<&Transform, With<Duckling>>,
+) {
+    if state.done {
+        return;
+    }
+    if duckling.translation.x > balance::POND.x - balance::SHORE_WIDTH {
+        state.done = true;
+        tx.write(NextGame::from_result(GameResult::Passsed));
+        info!("reached the shore - next game");
+    }
+}
@@ -4,19 +4,21 @@ use bevy::prelude::*;
 
 use crate::{
     AppSystems, PausableSystems,
-    asset_tracking::LoadResource,
-    games::{Game, GameControlMethod, GameInfo, GameResult, NextGame},
+    asset_manifest::{AssetManifest, FromManifest, ManifestError},
+    controls::progress_bar::{Fill, Pattern},
+    games::{Game, GameControlMethod, GameInfo, GameResult, LoadGameResource, NextGame},
+    localization::Localized,
     menus::MenuAssets,
     screens::Screen,
     theme::widget,
-    timeout::Timeout,
+    timeout::{Timeout, TimeoutBar, TimeoutLabel, WARNING_THRESHOLDS},
 };
 
 const GAME: Game = Game::Example;
 
 pub(super) fn plugin(app: &mut App) {
     // Register our assets to be loaded when the application is loading
-    app.load_resource::<ExampleAssets>();
+    app.load_game_manifest::<ExampleAssets>(GAME);
 
     // Register our spawn system to be triggered when this game is selected
     app.add_systems(OnEnter(GAME), spawn);
@@ -24,7 +26,7 @@ pub(super) fn plugin(app: &mut App) {
     // Register all systems that are to be run when this game is active
     app.add_systems(
         Update,
-        (update, update_countdown)
+        update
             .in_set(AppSystems::Update)
             .in_set(PausableSystems)
             .run_if(in_state(GAME)),
@@ -38,8 +40,9 @@ pub const fn get_info() -> GameInfo {
     GameInfo {
         kind: GAME,
         controls: GameControlMethod::Wasd,
-        hint: "Go",
+        hint: "hint.go",
         color: 0xFFFFFFFF,
+        pattern: Pattern::Solid,
     }
 }
 
@@ -59,33 +62,32 @@ impl ExampleState {
     }
 }
 
-/// Anything with this component will have its `Text` set to the countdown time
-#[derive(Debug, Default, Component)]
-#[require(Text)]
-struct ExampleCountdown;
-
 /// Used to track all assets for this game
 #[derive(Resource, Asset, Clone, Reflect)]
 #[reflect(Resource)]
 pub struct ExampleAssets {
     #[dependency]
     music: Handle<AudioSource>,
+    #[dependency]
+    tick: Handle<AudioSource>,
 }
 
-impl FromWorld for ExampleAssets {
-    /// Load all assets we want for this game
-    fn from_world(world: &mut World) -> Self {
-        let assets = world.resource::<AssetServer>();
-        Self {
-            music: assets.load("games/duck/Fluffing A Duck.ogg"),
-        }
+impl FromManifest for ExampleAssets {
+    const PATH: &'static str = "games/duck/example.manifest";
+
+    /// Pick all assets we want for this game out of the manifest
+    fn from_manifest(manifest: &AssetManifest) -> Result<Self, ManifestError> {
+        Ok(Self {
+            music: manifest.sound("music")?,
+            tick: manifest.sound("tick")?,
+        })
     }
 }
 
 /// A system to spawn the example level
 pub fn spawn(
     mut commands: Commands,
-    _assets: Res<ExampleAssets>,
+    assets: Res<ExampleAssets>,
     menu_assets: Res<MenuAssets>,
     mut state: ResMut<ExampleState>,
     time: Res<Time>,
@@ -96,13 +98,16 @@ pub fn spawn(
         widget::ui_root("Example Level"),
         DespawnOnExit(GAME), // When exiting this game despawn this entity
         DespawnOnExit(Screen::Gameplay), // When exiting the top level game despawn this entity
-        Timeout::default(),
+        Timeout::new(state.run_time),
         children![
-            widget::header("Example Game", menu_assets.font.clone()),
+            widget::header("example.title", menu_assets.font.clone()),
             (
-                widget::label("0", menu_assets.font.clone()),
-                ExampleCountdown
-            )
+                widget::label(Localized::verbatim(""), menu_assets.font.clone()),
+                TimeoutLabel::with_tick(assets.tick.clone()),
+            ),
+            TimeoutBar::default()
+                .with_fill(Fill::Radial)
+                .with_thresholds(WARNING_THRESHOLDS),
         ],
     ));
 }
@@ -114,20 +119,3 @@ pub fn update(state: Res<ExampleState>, time: Res<Time>, mut tx: MessageWriter<N
         info!("Next game");
     }
 }
-
-/// Update anything with the `ExampleCountdown` component to display the current countdown
-fn update_countdown(
-    mut query: Query<&mut Text, With<ExampleCountdown>>,
-    state: Res<ExampleState>,
-    time: Res<Time>,
-) {
-    for mut text in query.iter_mut() {
-        let elapsed = time.elapsed() - state.start_time;
-        let countdown = if elapsed < state.run_time {
-            (state.run_time - elapsed).as_secs_f32().ceil() as u32
-        } else {
-            0
-        };
-        text.0 = format!("{}", countdown)
-    }
-}
@@ -1,8 +1,30 @@
-use std::time::Duration;
+use std::{ops::Range, time::Duration};
+
+use bevy::math::Vec2;
 
 pub const GAME_DURATION: Duration = Duration::from_secs(5);
 pub const MIN_OPEN_DELAY: f32 = 1.0;
 pub const MAX_OPEN_DELAY: f32 = 3.0;
+// Seconds an oyster stays open at level 0, shorter each level down to MIN_OPEN_TIME
 pub const OPEN_TIME: f32 = 0.75;
+pub const MIN_OPEN_TIME: f32 = 0.35;
 
 pub const LEVEL_MULTIPLIER: f32 = 0.95;
+
+// Another oyster joins every this many levels, up to MAX_OYSTERS
+pub const LEVELS_PER_OYSTER: usize = 2;
+pub const MAX_OYSTERS: usize = 3;
+// Oysters are shrunk to fit when there is more than one
+pub const MANY_OYSTERS_SCALE: f32 = 0.6;
+
+// Level from which oysters twitch open for a moment without showing the pearl
+pub const TWITCH_LEVEL: usize = 1;
+pub const TWITCH_TIME: f32 = 0.12;
+// Seconds between twitches of an oyster
+pub const TWITCH_INTERVAL: Range<f32> = 0.5..1.2;
+
+// Where the lobster stops, relative to the oyster it goes for at full size
+pub const GRAB_OFFSET: Vec2 = Vec2 {
+    x: -130.0,
+    y: -10.0,
+};
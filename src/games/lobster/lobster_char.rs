@@ -2,20 +2,21 @@ use bevy::prelude::*;
 use std::time::Duration;
 
 use crate::audio::sound_effect;
-use crate::games::lobster::LobsterAssets;
+use crate::easing::Easing;
+use crate::games::lobster::{LobsterAssets, LobsterState, balance, oyster::Oyster};
+use crate::tween::{PausableTween, TranslationLens, Tween};
 
+/// Where the lobster lunges to when it tries to grab
 #[derive(Debug, Default, Component)]
 pub struct MoveToTarget {
     target: Vec2,
     time: f32,
-    start_time: Option<Duration>,
-    start_location: Vec2,
 }
 
 #[derive(Debug, Default, Component)]
 pub struct Lobster;
 
-pub fn lobster_char(assets: &LobsterAssets, pos: Vec2, target: Vec2) -> impl Bundle {
+pub fn lobster_char(assets: &LobsterAssets, pos: Vec2) -> impl Bundle {
     (
         Name::new("lobster"),
         Transform::from_xyz(pos.x, pos.y, 10.0).with_scale(Vec3 {
@@ -26,36 +27,49 @@ pub fn lobster_char(assets: &LobsterAssets, pos: Vec2, target: Vec2) -> impl Bun
         Visibility::default(),
         Sprite::from_image(assets.lobster.clone()),
         MoveToTarget {
-            target,
+            target: pos,
             time: 0.5,
-            start_time: None,
-            start_location: pos,
         },
         Lobster,
+        PausableTween,
     )
 }
 
-// todo: share this?
-pub fn update_move(time: Res<Time>, moveable_query: Query<(&mut Transform, &MoveToTarget)>) {
-    for (mut transform, target) in moveable_query {
-        // dumb lerp towards target
-        if let Some(start) = target.start_time {
-            let t = (time.elapsed() - start).div_f32(target.time);
-            let p = target
-                .start_location
-                .lerp(target.target, crate::easing::cubic_in_out(t.as_secs_f32()));
-            transform.translation.x = p.x;
-            transform.translation.y = p.y;
-        }
+/// Point the lobster at the selected oyster
+pub fn aim(
+    state: Res<LobsterState>,
+    oysters: Query<(&Oyster, &Transform)>,
+    mut lobster: Single<&mut MoveToTarget, With<Lobster>>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+    if let Some((_, transform)) = oysters
+        .iter()
+        .find(|(oyster, _)| oyster.slot == state.selected)
+    {
+        lobster.target = transform.translation.xy() + balance::GRAB_OFFSET * transform.scale.x;
     }
 }
 
+/// Lunge at the selected oyster, only once per game
 pub fn try_grab(
     mut commands: Commands,
     assets: Res<LobsterAssets>,
-    time: Res<Time>,
-    mut lobster: Single<&mut MoveToTarget, With<Lobster>>,
+    state: Res<LobsterState>,
+    lobster: Single<(Entity, &Transform, &MoveToTarget), With<Lobster>>,
 ) {
-    lobster.start_time = Some(time.elapsed());
+    if state.caught.is_some() {
+        return;
+    }
+    let (entity, transform, move_to) = lobster.into_inner();
+    commands.entity(entity).insert(Tween::new(
+        TranslationLens {
+            start: transform.translation,
+            end: move_to.target.extend(transform.translation.z),
+        },
+        Duration::from_secs_f32(move_to.time),
+        Easing::CubicInOut,
+    ));
     commands.spawn(sound_effect(assets.lobster_go.clone()));
 }
@@ -2,23 +2,20 @@ use rand::Rng;
 use std::time::Duration;
 
 use bevy::{
-    app::Propagate,
-    camera::ScalingMode,
-    image::{ImageLoaderSettings, ImageSampler},
-    input::common_conditions::input_just_pressed,
-    prelude::*,
+    app::Propagate, camera::ScalingMode, input::common_conditions::input_just_pressed, prelude::*,
 };
 
 use crate::{
     AppSystems, PausableSystems,
-    asset_tracking::LoadResource,
+    asset_manifest::{AssetManifest, FromManifest, ManifestError},
+    controls::progress_bar::{Fill, Pattern},
     games::{
-        Game, GameControlMethod, GameData, GameInfo, GameResult, NextGame,
+        Game, GameControlMethod, GameData, GameInfo, GameResult, LoadGameResource, NextGame,
         camera::{self, shake::CameraShakeConfig},
     },
     screens::Screen,
     theme::widget,
-    timeout::{TimedOut, Timeout, TimeoutBar},
+    timeout::{TimedOut, Timeout, TimeoutBar, WARNING_THRESHOLDS},
 };
 use crate::{color::color_u32, float::Floats};
 
@@ -30,7 +27,7 @@ const GAME: Game = Game::Lobster;
 
 pub(super) fn plugin(app: &mut App) {
     // Register our assets to be loaded when the application is loading
-    app.load_resource::<LobsterAssets>();
+    app.load_game_manifest::<LobsterAssets>(GAME);
 
     // Register our spawn system to be triggered when this game is selected
     app.add_systems(OnEnter(GAME), spawn);
@@ -40,10 +37,17 @@ pub(super) fn plugin(app: &mut App) {
         Update,
         (
             oyster::update,
+            oyster::update_twitch,
             oyster::play_sound_after_delay,
-            oyster::try_grab.run_if(input_just_pressed(KeyCode::Space)),
-            lobster_char::try_grab.run_if(input_just_pressed(KeyCode::Space)),
-            lobster_char::update_move,
+            (
+                oyster::select,
+                oyster::highlight_selected,
+                lobster_char::aim,
+                // The lobster checks for an earlier grab before the oyster records this one
+                lobster_char::try_grab.run_if(input_just_pressed(KeyCode::Space)),
+                oyster::try_grab.run_if(input_just_pressed(KeyCode::Space)),
+            )
+                .chain(),
         )
             .in_set(AppSystems::Update)
             .in_set(PausableSystems)
@@ -57,9 +61,14 @@ pub(super) fn plugin(app: &mut App) {
 pub const fn get_info() -> GameInfo {
     GameInfo {
         kind: GAME,
-        controls: GameControlMethod::Space,
-        hint: "Grab",
+        controls: GameControlMethod::Keys(&[
+            KeyCode::ArrowLeft,
+            KeyCode::ArrowRight,
+            KeyCode::Space,
+        ]),
+        hint: "hint.grab",
         color: 0x8746D0FF,
+        pattern: Pattern::Checker,
     }
 }
 
@@ -68,12 +77,17 @@ pub const fn get_info() -> GameInfo {
 pub struct LobsterState {
     pub start_time: Duration,
     pub caught: Option<bool>,
+    /// Slot of the oyster the lobster goes for
+    pub selected: usize,
+    pub oysters: usize,
 }
 
 impl LobsterState {
-    pub fn reset(&mut self, start_time: Duration) {
+    pub fn reset(&mut self, start_time: Duration, oysters: usize) {
         self.start_time = start_time;
         self.caught = None;
+        self.selected = 0;
+        self.oysters = oysters;
     }
 }
 
@@ -88,6 +102,8 @@ pub struct LobsterAssets {
     #[dependency]
     pub oyster: Handle<Image>,
     #[dependency]
+    pub oyster_layout: Handle<TextureAtlasLayout>,
+    #[dependency]
     pub pearl: Handle<Image>,
     #[dependency]
     pub pearl_hit_sfx: Handle<AudioSource>,
@@ -101,46 +117,23 @@ pub struct LobsterAssets {
     pub bgm: Handle<AudioSource>,
 }
 
-impl FromWorld for LobsterAssets {
-    /// Load all assets we want for this game
-    fn from_world(world: &mut World) -> Self {
-        let assets = world.resource::<AssetServer>();
-        Self {
-            lobster: assets.load_with_settings(
-                "games/lobster/lobster.png",
-                |settings: &mut ImageLoaderSettings| {
-                    settings.sampler = ImageSampler::nearest();
-                },
-            ),
-            shrimp: assets.load_with_settings(
-                "games/lobster/shrimp.png",
-                |settings: &mut ImageLoaderSettings| {
-                    settings.sampler = ImageSampler::nearest();
-                },
-            ),
-            oyster: assets.load_with_settings(
-                "games/lobster/oyster.png",
-                |settings: &mut ImageLoaderSettings| {
-                    settings.sampler = ImageSampler::nearest();
-                },
-            ),
-            pearl: assets.load_with_settings(
-                "games/lobster/pearl.png",
-                |settings: &mut ImageLoaderSettings| {
-                    settings.sampler = ImageSampler::nearest();
-                },
-            ),
-            pearl_hit_sfx: assets.load("games/lobster/pearl_hit.ogg"),
-            pearl_miss_sfx: assets.load("games/lobster/pearl_miss.ogg"),
-            lobster_go: assets.load("games/lobster/lobster_go.ogg"),
-            background: assets.load_with_settings(
-                "games/lobster/background.png",
-                |settings: &mut ImageLoaderSettings| {
-                    settings.sampler = ImageSampler::nearest();
-                },
-            ),
-            bgm: assets.load("games/lobster/bgm.ogg"),
-        }
+impl FromManifest for LobsterAssets {
+    const PATH: &'static str = "games/lobster/lobster.manifest";
+
+    /// Pick all assets we want for this game out of the manifest
+    fn from_manifest(manifest: &AssetManifest) -> Result<Self, ManifestError> {
+        Ok(Self {
+            lobster: manifest.image("lobster")?,
+            shrimp: manifest.image("shrimp")?,
+            oyster: manifest.image("oyster")?,
+            oyster_layout: manifest.layout("oyster")?,
+            pearl: manifest.image("pearl")?,
+            pearl_hit_sfx: manifest.sound("pearl_hit")?,
+            pearl_miss_sfx: manifest.sound("pearl_miss")?,
+            lobster_go: manifest.sound("lobster_go")?,
+            background: manifest.image("background")?,
+            bgm: manifest.sound("bgm")?,
+        })
     }
 }
 
@@ -151,9 +144,9 @@ pub fn spawn(
     assets: Res<LobsterAssets>,
     mut state: ResMut<LobsterState>,
     time: Res<Time>,
-    mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
 ) {
-    state.reset(time.elapsed());
+    let oyster_count = (1 + gamedata.level / balance::LEVELS_PER_OYSTER).min(balance::MAX_OYSTERS);
+    state.reset(time.elapsed(), oyster_count);
 
     commands.spawn((
         DespawnOnExit(GAME),             // When exiting this game despawn this entity
@@ -201,29 +194,28 @@ pub fn spawn(
     }
 
     let level_multiplier = f32::powi(balance::LEVEL_MULTIPLIER, gamedata.level as i32);
-    let open_time = balance::OPEN_TIME * level_multiplier;
-    let oyster = commands
-        .spawn(oyster::oyster(
-            &assets,
-            Vec2 { x: 240.0, y: -90.0 },
-            &mut texture_atlas_layouts,
-            open_time,
-        ))
-        .id();
+    let open_time = (balance::OPEN_TIME * level_multiplier).max(balance::MIN_OPEN_TIME);
+    let mut children = vec![level];
+    for slot in 0..oyster_count {
+        let (pos, scale) = oyster_placement(slot, oyster_count);
+        let mut oyster = commands.spawn(oyster::oyster(&assets, slot, pos, scale, open_time));
+        if gamedata.level >= balance::TWITCH_LEVEL {
+            oyster.insert(oyster::Twitch::new());
+        }
+        children.push(oyster.id());
+    }
 
-    let lobster = commands
-        .spawn(lobster_char::lobster_char(
-            &assets,
-            Vec2 {
-                x: -240.0,
-                y: -100.0,
-            },
-            Vec2 {
-                x: 110.0,
-                y: -100.0,
-            },
-        ))
-        .id();
+    children.push(
+        commands
+            .spawn(lobster_char::lobster_char(
+                &assets,
+                Vec2 {
+                    x: -240.0,
+                    y: -100.0,
+                },
+            ))
+            .id(),
+    );
 
     commands
         .spawn((
@@ -235,7 +227,7 @@ pub fn spawn(
             Propagate(camera::RENDERLAYER_GAME),
             AudioPlayer(assets.bgm.clone()),
         ))
-        .add_children(&[level, oyster, lobster]);
+        .add_children(&children);
 
     commands
         .spawn((
@@ -243,13 +235,27 @@ pub fn spawn(
             DespawnOnExit(GAME), // When exiting this game despawn this entity
             DespawnOnExit(Screen::Gameplay), // When exiting the top level game despawn this entity
             Timeout::new(balance::GAME_DURATION),
-            children![TimeoutBar::from_foreground_color(color_u32(
-                get_info().color
-            ))],
+            children![
+                // Shrinks like a closing shell
+                TimeoutBar::from_foreground_color(color_u32(get_info().color))
+                    .with_pattern(get_info().pattern)
+                    .with_fill(Fill::Radial)
+                    .with_thresholds(WARNING_THRESHOLDS)
+            ],
         ))
         .observe(timed_out);
 }
 
+/// Where the oyster in `slot` goes and how big it is, spread out to the right of the lobster
+fn oyster_placement(slot: usize, count: usize) -> (Vec2, f32) {
+    if count <= 1 {
+        return (Vec2 { x: 240.0, y: -90.0 }, 1.0);
+    }
+    let t = slot as f32 / (count - 1) as f32;
+    let x = 40.0 + (330.0 - 40.0) * t;
+    (Vec2 { x, y: -120.0 }, balance::MANY_OYSTERS_SCALE)
+}
+
 fn timed_out(_event: On<TimedOut>, mut tx: MessageWriter<NextGame>, state: Res<LobsterState>) {
     if let Some(caught) = state.caught
         && caught
@@ -8,9 +8,14 @@ use rand::Rng;
 
 use crate::games::lobster::{LobsterAssets, LobsterState, balance};
 
+/// Tint of the oysters that are not selected
+const UNSELECTED_COLOR: Color = Color::srgb(0.55, 0.55, 0.6);
+
 #[derive(Debug, Default, Component)]
 pub struct Oyster {
     is_open: bool,
+    /// Position from left to right, see [`LobsterState::selected`]
+    pub slot: usize,
 }
 
 #[derive(Component)]
@@ -25,37 +30,60 @@ pub struct OpenTimer(Timer);
 #[derive(Component, Deref, DerefMut)]
 pub struct CloseTimer(Timer);
 
+/// Briefly opens the oyster without the pearl every now and then, to fake out the player
+#[derive(Component)]
+pub struct Twitch {
+    next: Timer,
+    shown: Timer,
+}
+
+impl Twitch {
+    pub fn new() -> Self {
+        let mut shown = Timer::from_seconds(balance::TWITCH_TIME, TimerMode::Once);
+        shown.pause();
+        Self {
+            next: Timer::from_seconds(random_twitch_interval(), TimerMode::Once),
+            shown,
+        }
+    }
+}
+
+fn random_twitch_interval() -> f32 {
+    rand::rng().random_range(balance::TWITCH_INTERVAL)
+}
+
 pub fn oyster(
     assets: &LobsterAssets,
+    slot: usize,
     pos: Vec2,
-    texture_atlas_layouts: &mut Assets<TextureAtlasLayout>,
+    scale: f32,
     open_time: f32,
 ) -> impl Bundle {
     // use bevy random source?
     let mut rng = rand::rng();
     let delay = rng.random_range(balance::MIN_OPEN_DELAY..balance::MAX_OPEN_DELAY);
 
-    let layout = TextureAtlasLayout::from_grid(UVec2 { x: 220, y: 253 }, 3, 1, None, None);
-    let texture_atlas_layout = texture_atlas_layouts.add(layout);
-
     let mut close_timer = Timer::from_seconds(open_time, TimerMode::Once);
     close_timer.pause();
 
     (
         Name::new("oyster"),
-        Transform::from_xyz(pos.x, pos.y, 2.0),
+        Transform::from_xyz(pos.x, pos.y, 2.0).with_scale(Vec3::new(scale, scale, 1.0)),
         Visibility::default(),
         Sprite::from_atlas_image(
             assets.oyster.clone(),
             TextureAtlas {
-                layout: texture_atlas_layout,
+                layout: assets.oyster_layout.clone(),
                 index: 0,
             },
         ),
         AnimationIndices { first: 0, last: 1 },
         AnimationTimer(Timer::from_seconds(0.3, TimerMode::Repeating)),
         Pickable::default(),
-        Oyster::default(),
+        Oyster {
+            is_open: false,
+            slot,
+        },
         OpenTimer(Timer::from_seconds(delay, TimerMode::Once)),
         CloseTimer(close_timer),
         children![pearl(assets)],
@@ -74,69 +102,145 @@ fn pearl(assets: &LobsterAssets) -> impl Bundle {
 
 pub fn update(
     time: Res<Time>,
-    query: Single<(
+    mut oysters: Query<(
         &mut Oyster,
         &mut OpenTimer,
         &mut CloseTimer,
         &mut Sprite,
         &mut AnimationTimer,
     )>,
-    pearl: Single<&mut Visibility, With<Pearl>>,
+    mut pearls: Query<(&ChildOf, &mut Visibility), With<Pearl>>,
 ) {
-    let (mut oyster, mut open_timer, mut close_timer, mut sprite, mut anim_timer) =
-        query.into_inner();
-    open_timer.tick(time.delta());
-    close_timer.tick(time.delta());
-
-    let mut vis = pearl.into_inner();
-
-    if open_timer.just_finished()
-        && let Some(atlas) = &mut sprite.texture_atlas
-    {
-        atlas.index = 2;
-        anim_timer.pause();
-        close_timer.unpause();
-        oyster.is_open = true;
-        *vis = Visibility::Visible;
+    for (child_of, mut vis) in &mut pearls {
+        let Ok((mut oyster, mut open_timer, mut close_timer, mut sprite, mut anim_timer)) =
+            oysters.get_mut(child_of.parent())
+        else {
+            continue;
+        };
+        open_timer.tick(time.delta());
+        close_timer.tick(time.delta());
+
+        if open_timer.just_finished()
+            && let Some(atlas) = &mut sprite.texture_atlas
+        {
+            atlas.index = 2;
+            anim_timer.pause();
+            close_timer.unpause();
+            oyster.is_open = true;
+            *vis = Visibility::Visible;
+        }
+
+        if close_timer.just_finished()
+            && let Some(atlas) = &mut sprite.texture_atlas
+        {
+            atlas.index = 0;
+            oyster.is_open = false;
+            *vis = Visibility::Hidden;
+        }
+    }
+}
+
+/// Flash [`Twitch`] oysters open while they are not really open, until one is grabbed
+pub fn update_twitch(
+    time: Res<Time>,
+    state: Res<LobsterState>,
+    mut oysters: Query<(&Oyster, &mut Twitch, &mut Sprite, &mut AnimationTimer)>,
+) {
+    for (oyster, mut twitch, mut sprite, mut anim_timer) in &mut oysters {
+        if oyster.is_open {
+            continue;
+        }
+        let Some(atlas) = &mut sprite.texture_atlas else {
+            continue;
+        };
+
+        twitch.next.tick(time.delta());
+        twitch.shown.tick(time.delta());
+
+        // A twitch already showing still closes again
+        if twitch.next.just_finished() && state.caught.is_none() {
+            atlas.index = 2;
+            anim_timer.pause();
+            twitch.shown.reset();
+            twitch.shown.unpause();
+            twitch.next = Timer::from_seconds(random_twitch_interval(), TimerMode::Once);
+        }
+
+        if twitch.shown.just_finished() {
+            atlas.index = 0;
+            anim_timer.unpause();
+            twitch.shown.pause();
+        }
     }
+}
 
-    if close_timer.just_finished()
-        && let Some(atlas) = &mut sprite.texture_atlas
-    {
-        atlas.index = 0;
-        oyster.is_open = false;
-        *vis = Visibility::Hidden;
+/// Move the selection between oysters with the arrow keys
+pub fn select(keys: Res<ButtonInput<KeyCode>>, mut state: ResMut<LobsterState>) {
+    if state.caught.is_some() {
+        return;
+    }
+    if keys.just_pressed(KeyCode::ArrowLeft) {
+        state.selected = state.selected.saturating_sub(1);
+    }
+    if keys.just_pressed(KeyCode::ArrowRight) {
+        state.selected = (state.selected + 1).min(state.oysters.saturating_sub(1));
+    }
+}
+
+/// Dim all oysters but the selected one
+pub fn highlight_selected(state: Res<LobsterState>, mut oysters: Query<(&Oyster, &mut Sprite)>) {
+    if !state.is_changed() {
+        return;
+    }
+    for (oyster, mut sprite) in &mut oysters {
+        sprite.color = if oyster.slot == state.selected {
+            Color::WHITE
+        } else {
+            UNSELECTED_COLOR
+        };
     }
 }
 
 pub fn try_grab(
-    oyster: Single<(Entity, &Oyster, &mut CloseTimer, &mut OpenTimer)>,
-    pearl: Single<(Entity, &Pearl)>,
+    mut oysters: Query<(Entity, &Oyster, &mut CloseTimer, &mut OpenTimer)>,
+    pearls: Query<(Entity, &ChildOf), With<Pearl>>,
     mut state: ResMut<LobsterState>,
     mut commands: Commands,
     assets: Res<LobsterAssets>,
     time: Res<Time>,
 ) {
-    let (entity, oyster, mut close_timer, mut open_timer) = oyster.into_inner();
-    close_timer.pause();
-    open_timer.pause();
-    state.caught = Some(oyster.is_open);
-
-    let sfx = if oyster.is_open {
-        assets.pearl_hit_sfx.clone()
-    } else {
-        assets.pearl_miss_sfx.clone()
-    };
-    commands.entity(entity).insert(PlaySoundDelayed(
-        Timer::from_seconds(0.4, TimerMode::Once),
-        sfx,
-    ));
-
-    let (pearl_entity, _) = pearl.into_inner();
-    commands.entity(pearl_entity).insert(DespawnAfter::new(
-        time.elapsed(),
-        Duration::from_secs_f32(0.4),
-    ));
+    if state.caught.is_some() {
+        return;
+    }
+
+    for (entity, oyster, mut close_timer, mut open_timer) in &mut oysters {
+        close_timer.pause();
+        open_timer.pause();
+        if oyster.slot != state.selected {
+            continue;
+        }
+
+        state.caught = Some(oyster.is_open);
+
+        let sfx = if oyster.is_open {
+            assets.pearl_hit_sfx.clone()
+        } else {
+            assets.pearl_miss_sfx.clone()
+        };
+        commands.entity(entity).insert(PlaySoundDelayed(
+            Timer::from_seconds(0.4, TimerMode::Once),
+            sfx,
+        ));
+
+        for (pearl_entity, child_of) in &pearls {
+            if child_of.parent() == entity {
+                commands.entity(pearl_entity).insert(DespawnAfter::new(
+                    time.elapsed(),
+                    Duration::from_secs_f32(0.4),
+                ));
+            }
+        }
+    }
 }
 
 pub fn play_sound_after_delay(
@@ -4,17 +4,23 @@ use bevy::prelude::*;
 use rand::Rng;
 use rand::seq::SliceRandom;
 
-use crate::screens::Screen;
+use crate::{controls::progress_bar::Pattern, screens::Screen};
 
+mod assets;
 mod balance;
-mod camera;
+pub mod camera;
 mod cat_bonk;
 mod catch;
+mod duck;
 mod example;
 mod lobster;
 mod popup;
 mod pre_game;
+mod pump;
 mod rain;
+mod typing;
+
+pub use assets::{FailedGames, LoadGameResource};
 
 pub(super) fn plugin(app: &mut App) {
     app.init_state::<Game>();
@@ -26,6 +32,7 @@ pub(super) fn plugin(app: &mut App) {
 
     // Register all mini games here
     app.add_plugins((
+        assets::plugin,
         camera::plugin,
         pre_game::plugin,
         example::plugin,
@@ -34,6 +41,9 @@ pub(super) fn plugin(app: &mut App) {
         popup::plugin,
         lobster::plugin,
         rain::plugin,
+        typing::plugin,
+        duck::plugin,
+        pump::plugin,
     ));
 }
 
@@ -48,6 +58,9 @@ pub enum Game {
     Popup,
     Lobster,
     Rain,
+    Typing,
+    Duck,
+    Pump,
 }
 
 impl fmt::Display for Game {
@@ -64,6 +77,9 @@ impl fmt::Display for Game {
                 Game::Popup => "Popup",
                 Game::Lobster => "Lobster",
                 Game::Rain => "Rain",
+                Game::Typing => "Typing",
+                Game::Duck => "Duck",
+                Game::Pump => "Pump",
             }
         )
     }
@@ -82,14 +98,19 @@ pub enum GameState {
 pub struct GameInfo {
     pub kind: Game,
     pub controls: GameControlMethod,
+    /// Key of the instruction shown before the game, see [`crate::localization`]
     pub hint: &'static str,
     pub color: u32,
+    /// Shown with the color, so games can be told apart without it
+    pub pattern: Pattern,
 }
 
 #[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
 pub struct GameTransitionInfo {
     pub next: GameInfo,
     pub last: Option<GameResult>,
+    /// Key of why the last game was failed, if the game gave a reason
+    pub reason: Option<&'static str>,
 }
 
 #[allow(unused)]
@@ -100,21 +121,39 @@ pub enum GameControlMethod {
     Mouse,
     Keyboard,
     Space,
-    //Keys(Vec<KeyCode>),
+    /// Exactly these keys, each shown as a key cap before the game
+    Keys(&'static [KeyCode]),
 }
 
 impl fmt::Display for GameControlMethod {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::Wasd => "WASD",
-                Self::Mouse => "Mouse",
-                Self::Keyboard => "Keyboard",
-                Self::Space => "Space",
+        match self {
+            Self::Wasd => write!(f, "WASD"),
+            Self::Mouse => write!(f, "Mouse"),
+            Self::Keyboard => write!(f, "Keyboard"),
+            Self::Space => write!(f, "Space"),
+            Self::Keys(keys) => {
+                let names: Vec<_> = keys.iter().map(|&key| key_name(key)).collect();
+                write!(f, "{}", names.join(" "))
             }
-        )
+        }
+    }
+}
+
+/// Short English name of `key`, as printed on its key cap
+pub fn key_name(key: KeyCode) -> String {
+    let name = format!("{key:?}");
+    match key {
+        KeyCode::ArrowUp => "Up".to_string(),
+        KeyCode::ArrowDown => "Down".to_string(),
+        KeyCode::ArrowLeft => "Left".to_string(),
+        KeyCode::ArrowRight => "Right".to_string(),
+        KeyCode::Escape => "Esc".to_string(),
+        _ => name
+            .strip_prefix("Key")
+            .or_else(|| name.strip_prefix("Digit"))
+            .unwrap_or(&name)
+            .to_string(),
     }
 }
 
@@ -186,11 +225,24 @@ impl GameData {
 #[derive(Debug, Copy, Clone, Message)]
 pub struct NextGame {
     pub result: GameResult,
+    /// Key of the text explaining a failure, see [`GameTransitionInfo::reason`]
+    pub reason: Option<&'static str>,
 }
 
 impl NextGame {
     pub fn from_result(result: GameResult) -> Self {
-        Self { result }
+        Self {
+            result,
+            reason: None,
+        }
+    }
+
+    /// Fail with `reason`, a key of the text shown before the next game
+    pub fn failed_because(reason: &'static str) -> Self {
+        Self {
+            result: GameResult::Failed,
+            reason: Some(reason),
+        }
     }
 }
 
@@ -213,11 +265,28 @@ impl fmt::Display for GameResult {
     }
 }
 
-fn populate_game_pool(game_data: &mut ResMut<GameData>, current: Option<Game>) {
+/// Every game that can be picked, each comes up once before the pool is refilled
+const GAME_POOL: [Game; 8] = [
+    Game::Catch,
+    Game::CatBonk,
+    Game::Popup,
+    Game::Lobster,
+    Game::Rain,
+    Game::Typing,
+    Game::Duck,
+    Game::Pump,
+];
+
+fn populate_game_pool(
+    game_data: &mut ResMut<GameData>,
+    failed_games: &FailedGames,
+    current: Option<Game>,
+) {
     info!("refilling game pool");
 
     let mut rng = rand::rng();
-    let mut games = vec![Game::CatBonk, Game::Popup, Game::Lobster, Game::Rain];
+    let mut games = GAME_POOL.to_vec();
+    games.retain(|game| !failed_games.0.contains(game));
     games.shuffle(&mut rng);
 
     // make sure we don't have the same game we just played first in the pool
@@ -234,14 +303,30 @@ fn populate_game_pool(game_data: &mut ResMut<GameData>, current: Option<Game>) {
 /// A system that triggers the first game to spawn
 pub fn spawn_first(
     mut game_data: ResMut<GameData>,
+    failed_games: Res<FailedGames>,
     mut next_game: ResMut<NextState<Game>>,
     mut next_game_state: ResMut<NextState<GameState>>,
+    mut next_screen: ResMut<NextState<Screen>>,
 ) {
     game_data.reset();
+
+    let first = if failed_games.0.contains(&Game::Rain) {
+        populate_game_pool(&mut game_data, &failed_games, None);
+        game_data.game_pool.pop()
+    } else {
+        Some(Game::Rain)
+    };
+    let Some(first) = first else {
+        error!("No playable games, all of them failed to load");
+        next_screen.set(Screen::PostGame);
+        return;
+    };
+
     next_game.set(Game::Pre);
     next_game_state.set(GameState::PreGame(GameTransitionInfo {
-        next: get_info(Game::Rain),
+        next: get_info(first),
         last: None,
+        reason: None,
     }));
 }
 
@@ -251,6 +336,7 @@ fn spawn_next(
     mut rx: MessageReader<NextGame>,
     game: Res<State<Game>>,
     mut game_data: ResMut<GameData>,
+    failed_games: Res<FailedGames>,
     mut next_game: ResMut<NextState<Game>>,
     mut next_game_state: ResMut<NextState<GameState>>,
     mut next_screen: ResMut<NextState<Screen>>,
@@ -266,10 +352,14 @@ fn spawn_next(
         }
 
         if game_data.game_pool.is_empty() {
-            populate_game_pool(&mut game_data, Some(current));
+            populate_game_pool(&mut game_data, &failed_games, Some(current));
         }
 
-        let next_game_kind = game_data.game_pool.pop().unwrap();
+        let Some(next_game_kind) = game_data.game_pool.pop() else {
+            error!("No playable games left, all of them failed to load");
+            next_screen.set(Screen::PostGame);
+            continue;
+        };
         game_data.apply_result(game.result, Duration::from_secs(5)); // TODO: Actually time passed between games?
 
         if game_data.dead() {
@@ -280,6 +370,7 @@ fn spawn_next(
             next_game_state.set(GameState::PreGame(GameTransitionInfo {
                 next: get_info(next_game_kind),
                 last: Some(game.result),
+                reason: game.reason,
             }));
 
             info!(
@@ -300,5 +391,8 @@ const fn get_info(game: Game) -> GameInfo {
         Game::Popup => popup::get_info(),
         Game::Lobster => lobster::get_info(),
         Game::Rain => rain::get_info(),
+        Game::Typing => typing::get_info(),
+        Game::Duck => duck::get_info(),
+        Game::Pump => pump::get_info(),
     }
 }
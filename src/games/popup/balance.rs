@@ -5,3 +5,19 @@ pub const MAX_SPAWN_DELAY_MULTIPLIER: f64 = 0.25;
 
 pub const NUM_POPUPS: usize = 4;
 pub const POPUPS_PER_LEVEL: usize = 1;
+
+// Levels from which each behavior shows up, becoming more likely every level after
+pub const RANDOM_CORNER_LEVEL: usize = 1;
+pub const DODGE_LEVEL: usize = 2;
+pub const FAKE_CLOSE_LEVEL: usize = 2;
+pub const BLOCKER_LEVEL: usize = 3;
+pub const CHANCE_PER_LEVEL: f64 = 0.2;
+pub const MAX_CHANCE: f64 = 0.6;
+
+pub const BLOCKERS_PER_LEVEL: usize = 1;
+pub const MAX_BLOCKERS: usize = 3;
+// Popups opened by clicking a fake close button
+pub const FAKE_CLOSE_SPAWNS: usize = 2;
+// Windows dodge while the cursor is this close to their close button
+pub const DODGE_RADIUS: f32 = 30.0;
+pub const DODGE_SPEED: f32 = 90.0;
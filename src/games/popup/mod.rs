@@ -1,33 +1,33 @@
 use std::time::Duration;
 
-use bevy::{
-    app::Propagate,
-    camera::ScalingMode,
-    image::{ImageLoaderSettings, ImageSampler},
-    prelude::*,
-};
+use bevy::{app::Propagate, camera::ScalingMode, prelude::*};
+use rand::Rng;
 
 use crate::{
     AppSystems, PausableSystems,
-    asset_tracking::LoadResource,
+    asset_manifest::{AssetManifest, FromManifest, ManifestError},
     color::color_u32,
+    controls::progress_bar::Pattern,
     games::{
-        Game, GameControlMethod, GameData, GameInfo, GameResult, NextGame,
+        Game, GameControlMethod, GameData, GameInfo, GameResult, LoadGameResource, NextGame,
         camera::{self, shake::CameraShakeConfig},
+        popup::popup_window::Behavior,
     },
     screens::Screen,
     theme::widget,
-    timeout::{TimedOut, Timeout, TimeoutBar},
+    timeout::{TimedOut, Timeout, TimeoutBar, WARNING_THRESHOLDS},
 };
 
 mod balance;
 mod popup_window;
 
 const GAME: Game = Game::Popup;
+/// Draw order of windows that have to be dragged aside, above all popups
+const BLOCKER_ORDER: f32 = 500.0;
 
 pub(super) fn plugin(app: &mut App) {
     // Register our assets to be loaded when the application is loading
-    app.load_resource::<PopupAssets>();
+    app.load_game_manifest::<PopupAssets>(GAME);
 
     // Register our spawn system to be triggered when this game is selected
     app.add_systems(OnEnter(GAME), spawn);
@@ -35,7 +35,7 @@ pub(super) fn plugin(app: &mut App) {
     // Register all systems that are to be run when this game is active
     app.add_systems(
         Update,
-        (update, popup_window::update)
+        (update, popup_window::update, popup_window::update_dodge)
             .in_set(AppSystems::Update)
             .in_set(PausableSystems)
             .run_if(in_state(GAME)),
@@ -49,8 +49,9 @@ pub const fn get_info() -> GameInfo {
     GameInfo {
         kind: GAME,
         controls: GameControlMethod::Mouse,
-        hint: "Close",
+        hint: "hint.close",
         color: 0x5555FFFF,
+        pattern: Pattern::Dots,
     }
 }
 
@@ -59,14 +60,43 @@ pub const fn get_info() -> GameInfo {
 pub struct PopupState {
     pub start_time: Duration,
     pub remaining: usize,
+    pub level: usize,
+    /// Popups spawned so far, newer ones are drawn on top
+    pub spawned: usize,
+    /// Parent of all popups
+    pub background: Option<Entity>,
 }
 
 impl PopupState {
     /// Called when starting this game to make sure the data is reset
     /// Assuming that is what we want.
-    pub fn reset(&mut self, start_time: Duration, level: usize) {
+    pub fn reset(&mut self, start_time: Duration, level: usize, background: Entity) {
         self.start_time = start_time;
         self.remaining = balance::NUM_POPUPS + (level * balance::POPUPS_PER_LEVEL);
+        self.level = level;
+        self.spawned = 0;
+        self.background = Some(background);
+    }
+
+    /// Pick extra behavior for a popup, more likely at higher levels
+    pub fn pick_behavior(&self) -> Behavior {
+        let mut rng = rand::rng();
+        let mut roll = |from: usize| {
+            let chance = ((self.level + 1).saturating_sub(from) as f64 * balance::CHANCE_PER_LEVEL)
+                .min(balance::MAX_CHANCE);
+            rng.random_bool(chance)
+        };
+        Behavior {
+            random_corner: roll(balance::RANDOM_CORNER_LEVEL),
+            fake_close: roll(balance::FAKE_CLOSE_LEVEL),
+            dodge: roll(balance::DODGE_LEVEL),
+        }
+    }
+
+    fn blocker_count(&self) -> usize {
+        ((self.level + 1).saturating_sub(balance::BLOCKER_LEVEL) * balance::BLOCKERS_PER_LEVEL)
+            .min(balance::MAX_BLOCKERS)
+            .min(self.remaining)
     }
 }
 
@@ -86,77 +116,18 @@ pub struct PopupAssets {
     pub bgm: Handle<AudioSource>,
 }
 
-impl FromWorld for PopupAssets {
-    /// Load all assets we want for this game
-    fn from_world(world: &mut World) -> Self {
-        let assets = world.resource::<AssetServer>();
-        Self {
-            popups: vec![
-                assets.load_with_settings(
-                    "games/popup/popup_large1.png",
-                    |settings: &mut ImageLoaderSettings| {
-                        settings.sampler = ImageSampler::nearest();
-                    },
-                ),
-                assets.load_with_settings(
-                    "games/popup/popup_large2.png",
-                    |settings: &mut ImageLoaderSettings| {
-                        settings.sampler = ImageSampler::nearest();
-                    },
-                ),
-                assets.load_with_settings(
-                    "games/popup/popup_large3.png",
-                    |settings: &mut ImageLoaderSettings| {
-                        settings.sampler = ImageSampler::nearest();
-                    },
-                ),
-                assets.load_with_settings(
-                    "games/popup/popup_long1.png",
-                    |settings: &mut ImageLoaderSettings| {
-                        settings.sampler = ImageSampler::nearest();
-                    },
-                ),
-                assets.load_with_settings(
-                    "games/popup/popup_long2.png",
-                    |settings: &mut ImageLoaderSettings| {
-                        settings.sampler = ImageSampler::nearest();
-                    },
-                ),
-                assets.load_with_settings(
-                    "games/popup/popup_long3.png",
-                    |settings: &mut ImageLoaderSettings| {
-                        settings.sampler = ImageSampler::nearest();
-                    },
-                ),
-                assets.load_with_settings(
-                    "games/popup/popup_wide1.png",
-                    |settings: &mut ImageLoaderSettings| {
-                        settings.sampler = ImageSampler::nearest();
-                    },
-                ),
-                assets.load_with_settings(
-                    "games/popup/popup_wide2.png",
-                    |settings: &mut ImageLoaderSettings| {
-                        settings.sampler = ImageSampler::nearest();
-                    },
-                ),
-                assets.load_with_settings(
-                    "games/popup/popup_wide3.png",
-                    |settings: &mut ImageLoaderSettings| {
-                        settings.sampler = ImageSampler::nearest();
-                    },
-                ),
-            ],
-            background: assets.load_with_settings(
-                "games/popup/popup_background1.png",
-                |settings: &mut ImageLoaderSettings| {
-                    settings.sampler = ImageSampler::nearest();
-                },
-            ),
-            notify_sound: assets.load("games/popup/notify.ogg"),
-            close_sound: assets.load("games/popup/close.ogg"),
-            bgm: assets.load("games/popup/bgm.ogg"),
-        }
+impl FromManifest for PopupAssets {
+    const PATH: &'static str = "games/popup/popup.manifest";
+
+    /// Pick all assets we want for this game out of the manifest
+    fn from_manifest(manifest: &AssetManifest) -> Result<Self, ManifestError> {
+        Ok(Self {
+            popups: manifest.images("popups")?,
+            background: manifest.image("background")?,
+            notify_sound: manifest.sound("notify")?,
+            close_sound: manifest.sound("close")?,
+            bgm: manifest.sound("bgm")?,
+        })
     }
 }
 
@@ -168,7 +139,6 @@ pub fn spawn(
     mut state: ResMut<PopupState>,
     time: Res<Time>,
 ) {
-    state.reset(time.elapsed(), gamedata.level);
     commands.spawn((
         DespawnOnExit(GAME),             // When exiting this game despawn this entity
         DespawnOnExit(Screen::Gameplay), // When exiting the top level game despawn this entity
@@ -196,14 +166,35 @@ pub fn spawn(
             Visibility::default(),
             Sprite::from_image(assets.background.clone()),
         ))
-        .with_children(|parent| {
-            for i in 0..state.remaining {
-                parent
-                    .spawn(popup_window::popup_window(&assets, i + 1))
-                    .observe(popup_window::on_hit);
-            }
-        })
         .id();
+    state.reset(time.elapsed(), gamedata.level, level);
+
+    let mut rng = rand::rng();
+    let max_delay = balance::GAME_DURATION.as_secs_f64() * balance::MAX_SPAWN_DELAY_MULTIPLIER;
+    let blockers = state.blocker_count();
+    for i in 0..state.remaining {
+        state.spawned += 1;
+        let delay = Duration::from_secs_f64(rng.random_range(0.0..max_delay));
+        let position = popup_window::spawn_popup(
+            &mut commands,
+            &assets,
+            level,
+            state.spawned as f32,
+            delay,
+            state.pick_behavior(),
+        );
+        // Blockers go on top of everything, including popups opened later
+        if i < blockers {
+            popup_window::spawn_blocker(
+                &mut commands,
+                &assets,
+                level,
+                position,
+                BLOCKER_ORDER + i as f32,
+                delay,
+            );
+        }
+    }
 
     commands
         .spawn((
@@ -223,9 +214,11 @@ pub fn spawn(
             DespawnOnExit(Screen::Gameplay), // When exiting the top level game despawn this entity
             Timeout::new(balance::GAME_DURATION),
             AudioPlayer(assets.bgm.clone()),
-            children![TimeoutBar::from_foreground_color(color_u32(
-                get_info().color
-            ))],
+            children![
+                TimeoutBar::from_foreground_color(color_u32(get_info().color))
+                    .with_pattern(get_info().pattern)
+                    .with_thresholds(WARNING_THRESHOLDS)
+            ],
         ))
         .observe(timed_out);
 }
@@ -6,13 +6,125 @@ use crate::audio::sound_effect;
 use crate::float::Floats;
 use crate::games::{popup::PopupAssets, popup::PopupState, popup::balance};
 
+/// Size of the world shown by the game camera
+const SCREEN: Vec2 = Vec2 { x: 400.0, y: 225.0 };
+const CLOSE_SIZE: f32 = 17.0;
+
 #[derive(Debug, Default, Component)]
 pub struct PopupWindow {
-    close: Rect,
+    /// Where the close button is, relative to the center. Windows without one can't be closed.
+    close: Option<Rect>,
+    size: Vec2,
     popup_delay: Duration,
 }
 
-pub fn popup_window(assets: &PopupAssets, index: usize) -> impl Bundle {
+/// A window without a close button covering another one, it has to be dragged aside
+#[derive(Debug, Default, Component)]
+pub struct Draggable;
+
+/// A second close button that opens more popups instead
+#[derive(Debug, Component)]
+pub struct FakeClose {
+    button: Rect,
+}
+
+/// A window that moves away when the cursor comes near its close button
+#[derive(Debug, Default, Component)]
+pub struct Dodge;
+
+/// How a popup behaves, picked by [`PopupState::pick_behavior`]
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Behavior {
+    /// Mirror the window so its close button ends up in a random corner
+    pub random_corner: bool,
+    pub fake_close: bool,
+    pub dodge: bool,
+}
+
+/// Spawn a popup under `parent`, shown after `delay` since the start of the game.
+/// Returns where it was placed.
+pub fn spawn_popup(
+    commands: &mut Commands,
+    assets: &PopupAssets,
+    parent: Entity,
+    order: f32,
+    delay: Duration,
+    behavior: Behavior,
+) -> Vec2 {
+    let mut rng = rand::rng();
+    let (mut sprite, size) = window_sprite(assets);
+    let position = random_position(size);
+
+    let mut close = Rect::from_center_size((size / 2.0) - 8.0, Vec2::splat(CLOSE_SIZE));
+    if behavior.random_corner {
+        sprite.flip_x = rng.random_bool(0.5);
+        sprite.flip_y = rng.random_bool(0.5);
+        close = flip(close, sprite.flip_x, sprite.flip_y);
+    }
+
+    let mut popup = commands.spawn((
+        Name::new("popup"),
+        Transform::from_translation(position.extend(order)),
+        Visibility::Hidden,
+        sprite,
+        Pickable::default(),
+        PopupWindow {
+            close: Some(close),
+            size,
+            popup_delay: delay,
+        },
+    ));
+    popup.observe(on_hit);
+
+    if behavior.fake_close {
+        // Opposite side of the title bar from the real one
+        let button = flip(close, true, false);
+        popup
+            .insert(FakeClose { button })
+            .with_child(fake_button(button));
+    }
+    if behavior.dodge {
+        popup.insert(Dodge);
+    } else {
+        popup.insert(Floats);
+    }
+
+    let popup = popup.id();
+    commands.entity(parent).add_child(popup);
+    position
+}
+
+/// Spawn a window without a close button over the popup at `target`, shown with it
+pub fn spawn_blocker(
+    commands: &mut Commands,
+    assets: &PopupAssets,
+    parent: Entity,
+    target: Vec2,
+    order: f32,
+    delay: Duration,
+) {
+    let (sprite, size) = window_sprite(assets);
+    let blocker = commands
+        .spawn((
+            Name::new("blocker"),
+            Transform::from_translation(target.extend(order)),
+            Visibility::Hidden,
+            sprite,
+            Pickable::default(),
+            PopupWindow {
+                close: None,
+                size,
+                popup_delay: delay,
+            },
+            Draggable,
+        ))
+        .observe(on_drag)
+        .id();
+    commands.entity(parent).add_child(blocker);
+}
+
+/// Pick one of the popup images at random, with its size
+fn window_sprite(assets: &PopupAssets) -> (Sprite, Vec2) {
     let mut rng = rand::rng();
     let idx = rng.random_range(0..assets.popups.len());
     let asset = assets.popups[idx].clone();
@@ -23,30 +135,50 @@ pub fn popup_window(assets: &PopupAssets, index: usize) -> impl Bundle {
         6..9 => Vec2 { x: 150.0, y: 55.0 },
         _ => Vec2::default(),
     };
+    (Sprite::from_image(asset), size)
+}
 
-    let screen = Vec2 { x: 400.0, y: 225.0 };
-    let max = ((screen / 2.0) - (size / 2.0)) * 0.9;
-
-    let x = rng.random_range(-max.x..max.x);
-    let y = rng.random_range(-max.y..max.y);
-    let close_loc = Rect::from_center_size((size / 2.0) - 8.0, Vec2::splat(17.0));
+/// Furthest a window of `size` can be from the center while staying on screen
+fn max_offset(size: Vec2) -> Vec2 {
+    (((SCREEN / 2.0) - (size / 2.0)) * 0.9).max(Vec2::ZERO)
+}
 
-    let max = balance::GAME_DURATION.as_secs_f64() * balance::MAX_SPAWN_DELAY_MULTIPLIER;
-    let delay = rng.random_range(0.0..max);
+fn random_position(size: Vec2) -> Vec2 {
+    let mut rng = rand::rng();
+    let max = max_offset(size) + Vec2::splat(f32::EPSILON);
+    Vec2::new(
+        rng.random_range(-max.x..max.x),
+        rng.random_range(-max.y..max.y),
+    )
+}
 
-    println!("{} {}", size, close_loc.center());
+fn flip(rect: Rect, x: bool, y: bool) -> Rect {
+    let center = rect.center();
+    let center = Vec2::new(
+        if x { -center.x } else { center.x },
+        if y { -center.y } else { center.y },
+    );
+    Rect::from_center_size(center, rect.size())
+}
 
+/// A close button drawn over the window, made of a red square and a white cross
+fn fake_button(button: Rect) -> impl Bundle {
+    let bar = |angle: f32| {
+        (
+            Transform::from_xyz(0.0, 0.0, 0.1).with_rotation(Quat::from_rotation_z(angle)),
+            Sprite::from_color(Color::WHITE, Vec2::new(CLOSE_SIZE * 0.8, 2.0)),
+            Pickable::IGNORE,
+        )
+    };
     (
-        Name::new("popup"),
-        Transform::from_xyz(x, y, index as f32),
-        Visibility::Hidden,
-        Sprite::from_image(asset),
-        Pickable::default(),
-        PopupWindow {
-            close: close_loc,
-            popup_delay: Duration::from_secs_f64(delay),
-        },
-        Floats,
+        Name::new("fake_close"),
+        Transform::from_translation(button.center().extend(0.1)),
+        Sprite::from_color(Color::srgb(0.8, 0.1, 0.1), button.size()),
+        Pickable::IGNORE,
+        children![
+            bar(std::f32::consts::FRAC_PI_4),
+            bar(-std::f32::consts::FRAC_PI_4)
+        ],
     )
 }
 
@@ -56,9 +188,10 @@ pub fn on_hit(
     assets: Res<PopupAssets>,
     mut state: ResMut<PopupState>,
     query: Query<&GlobalTransform>,
-    popup_query: Query<&PopupWindow>,
+    popup_query: Query<(&PopupWindow, Option<&FakeClose>)>,
+    time: Res<Time>,
 ) {
-    if let Ok(popup) = popup_query.get(click.entity)
+    if let Ok((popup, fake_close)) = popup_query.get(click.entity)
         && let Some(world_pos) = click.hit.position
         && let Ok(transform) = query.get(click.entity)
     {
@@ -69,14 +202,48 @@ pub fn on_hit(
             y: local_pos.y,
         };
 
-        if popup.close.contains(local_2d) {
+        if popup.close.is_some_and(|close| close.contains(local_2d)) {
             commands.entity(click.entity).despawn();
             commands.spawn(sound_effect(assets.close_sound.clone()));
             state.remaining -= 1;
+        } else if let Some(fake_close) = fake_close
+            && fake_close.button.contains(local_2d)
+            && let Some(parent) = state.background
+        {
+            let elapsed = time.elapsed() - state.start_time;
+            for _ in 0..balance::FAKE_CLOSE_SPAWNS {
+                state.remaining += 1;
+                state.spawned += 1;
+                let order = state.spawned as f32;
+                let behavior = state.pick_behavior();
+                spawn_popup(&mut commands, &assets, parent, order, elapsed, behavior);
+            }
         }
     }
 }
 
+fn on_drag(
+    drag: On<Pointer<Drag>>,
+    mut query: Query<(&mut Transform, &PopupWindow), With<Draggable>>,
+    camera: Single<(&Camera, &GlobalTransform), Without<IsDefaultUiCamera>>,
+) {
+    let Ok((mut transform, popup)) = query.get_mut(drag.entity) else {
+        return;
+    };
+    let (camera, camera_transform) = *camera;
+    let position = drag.pointer_location.position;
+    let (Ok(now), Ok(before)) = (
+        camera.viewport_to_world_2d(camera_transform, position),
+        camera.viewport_to_world_2d(camera_transform, position - drag.delta),
+    ) else {
+        return;
+    };
+
+    let max = max_offset(popup.size);
+    let moved = (transform.translation.xy() + now - before).clamp(-max, max);
+    transform.translation = moved.extend(transform.translation.z);
+}
+
 // todo: pop up after random time period
 pub fn update(
     mut commands: Commands,
@@ -94,3 +261,39 @@ pub fn update(
         }
     }
 }
+
+/// Move [`Dodge`] windows away from the cursor while it nears their close button
+pub fn update_dodge(
+    time: Res<Time>,
+    mut popups: Query<(&PopupWindow, &mut Transform, &Visibility), With<Dodge>>,
+    camera: Single<(&Camera, &GlobalTransform), Without<IsDefaultUiCamera>>,
+    window: Single<&Window>,
+) {
+    let (camera, camera_transform) = *camera;
+    let Some(cursor) = window
+        .cursor_position()
+        .and_then(|position| camera.viewport_to_world_2d(camera_transform, position).ok())
+    else {
+        return;
+    };
+
+    for (popup, mut transform, visibility) in &mut popups {
+        let Some(close) = popup.close else {
+            continue;
+        };
+        if *visibility == Visibility::Hidden {
+            continue;
+        }
+
+        let position = transform.translation.xy();
+        let away = position + close.center() - cursor;
+        if away.length() > balance::DODGE_RADIUS {
+            continue;
+        }
+
+        let step = away.normalize_or(Vec2::X) * balance::DODGE_SPEED * time.delta_secs();
+        let max = max_offset(popup.size);
+        let moved = (position + step).clamp(-max, max);
+        transform.translation = moved.extend(transform.translation.z);
+    }
+}
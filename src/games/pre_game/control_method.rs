@@ -1,8 +1,19 @@
-use crate::{asset_tracking::LoadResource, float::Floats, games::GameControlMethod};
+use crate::{
+    asset_tracking::LoadResource,
+    float::Floats,
+    games::{GameControlMethod, key_name},
+    localization::Localized,
+    theme::palette::{BaseTextColor, LABEL_TEXT},
+};
 use bevy::prelude::*;
 
+const KEYCAP_SIZE: f32 = 90.0;
+/// Width of the rounded edge in `keycap.png`, kept unstretched when the cap is sized
+const KEYCAP_BORDER: f32 = 12.0;
+
 pub(super) fn plugin(app: &mut App) {
     app.load_resource::<ControlMethodAssets>();
+    app.add_systems(Update, spawn_icon);
 }
 
 /// Used to track all assets for this game
@@ -17,6 +28,8 @@ pub struct ControlMethodAssets {
     wasd: Handle<Image>,
     #[dependency]
     space: Handle<Image>,
+    #[dependency]
+    keycap: Handle<Image>,
 }
 
 impl FromWorld for ControlMethodAssets {
@@ -28,23 +41,92 @@ impl FromWorld for ControlMethodAssets {
             mouse: assets.load("games/pre_game/mouse.png"),
             wasd: assets.load("games/pre_game/wasd.png"),
             space: assets.load("games/pre_game/space.png"),
+            keycap: assets.load("games/pre_game/keycap.png"),
         }
     }
 }
 
-pub fn control_method(method: GameControlMethod, assets: &ControlMethodAssets) -> impl Bundle {
+/// Shows how a game is controlled, filled in once spawned
+#[derive(Debug, Component)]
+struct ControlMethodIcon {
+    method: GameControlMethod,
+    /// Used for the labels of [`GameControlMethod::Keys`]
+    font: Handle<Font>,
+}
+
+pub fn control_method(method: GameControlMethod, font: Handle<Font>) -> impl Bundle {
     (
         Name::new("control_method"),
-        ImageNode::new(asset_from_method(method, assets)),
+        Node {
+            column_gap: px(16),
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        ControlMethodIcon { method, font },
         Floats,
     )
 }
 
-fn asset_from_method(method: GameControlMethod, assets: &ControlMethodAssets) -> Handle<Image> {
-    match method {
-        GameControlMethod::Wasd => assets.wasd.clone(),
-        GameControlMethod::Mouse => assets.mouse.clone(),
-        GameControlMethod::Keyboard => assets.wasd.clone(),
-        GameControlMethod::Space => assets.space.clone(),
+fn spawn_icon(
+    mut commands: Commands,
+    query: Query<(Entity, &ControlMethodIcon), Added<ControlMethodIcon>>,
+    assets: Res<ControlMethodAssets>,
+) {
+    for (entity, icon) in query.iter() {
+        let image = match icon.method {
+            GameControlMethod::Wasd => assets.wasd.clone(),
+            GameControlMethod::Mouse => assets.mouse.clone(),
+            GameControlMethod::Keyboard => assets.keyboard.clone(),
+            GameControlMethod::Space => assets.space.clone(),
+            GameControlMethod::Keys(keys) => {
+                commands.entity(entity).with_children(|parent| {
+                    for &key in keys {
+                        parent.spawn(keycap(key, assets.keycap.clone(), icon.font.clone()));
+                    }
+                });
+                continue;
+            }
+        };
+        commands.entity(entity).insert(ImageNode::new(image));
+    }
+}
+
+/// A single key drawn as a sliced cap sprite with its label
+fn keycap(key: KeyCode, image: Handle<Image>, font: Handle<Font>) -> impl Bundle {
+    (
+        Name::new("keycap"),
+        Node {
+            min_width: px(KEYCAP_SIZE),
+            height: px(KEYCAP_SIZE),
+            padding: UiRect::horizontal(px(16)).with_bottom(px(8)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        ImageNode::new(image).with_mode(NodeImageMode::Sliced(TextureSlicer {
+            border: BorderRect::all(KEYCAP_BORDER),
+            ..default()
+        })),
+        Pickable::IGNORE,
+        children![(
+            Text::default(),
+            key_label(key),
+            TextFont::from(font).with_font_size(32.0),
+            TextColor(LABEL_TEXT),
+            BaseTextColor(LABEL_TEXT),
+        )],
+    )
+}
+
+/// Text printed on the cap of `key`, translated for the keys that have a name
+fn key_label(key: KeyCode) -> Localized {
+    match key {
+        KeyCode::ArrowUp => Localized::new("key.up"),
+        KeyCode::ArrowDown => Localized::new("key.down"),
+        KeyCode::ArrowLeft => Localized::new("key.left"),
+        KeyCode::ArrowRight => Localized::new("key.right"),
+        KeyCode::Space => Localized::new("key.space"),
+        KeyCode::Escape => Localized::new("key.escape"),
+        _ => Localized::verbatim(key_name(key)),
     }
 }
@@ -5,13 +5,14 @@ use bevy::prelude::*;
 use crate::{
     AppSystems, PausableSystems,
     color::color_u32,
+    controls::progress_bar::ProgressBar,
     games::{
         GameState,
         pre_game::{GAME, balance},
     },
     layout,
     menus::MenuAssets,
-    theme::widget,
+    theme::{palette::ColorMode, widget},
 };
 
 #[derive(Debug, Copy, Clone, Component)]
@@ -33,6 +34,7 @@ pub fn spawn(
     game_state: Res<State<GameState>>,
     time: Res<Time>,
     menu_assets: Res<MenuAssets>,
+    color_mode: Res<ColorMode>,
 ) {
     if let GameState::PreGame(game) = game_state.get() {
         info!("Hint spawn");
@@ -42,11 +44,28 @@ pub fn spawn(
             ZIndex(2),
             children![(
                 layout::top_center(),
-                widget::header_with_color(
-                    game.next.hint,
-                    color_u32(game.next.color),
-                    menu_assets.font.clone()
-                )
+                children![
+                    widget::header_with_color(
+                        game.next.hint,
+                        color_u32(game.next.color),
+                        menu_assets.font.clone()
+                    ),
+                    (
+                        Name::new("Hint Pattern"),
+                        Node {
+                            width: percent(100),
+                            height: px(16),
+                            ..default()
+                        },
+                        // Same pattern as the timeout bar of the game
+                        ProgressBar {
+                            color: color_mode.adjust(color_u32(game.next.color)),
+                            pattern: game.next.pattern,
+                            progress: 1.0,
+                            ..default()
+                        },
+                    ),
+                ],
             )],
             Hint {
                 display_time: time.elapsed() + balance::HINT_DISPLAY_TIME,
@@ -3,14 +3,18 @@ use std::time::Duration;
 use bevy::prelude::*;
 
 use crate::{
+    AppSystems,
     asset_tracking::LoadResource,
     backgrounds::BackgroundAssets,
     color::color_u32,
     games::{
-        Game, GameData, GameInfo, GameResult, GameState,
-        pre_game::control_method::{ControlMethodAssets, control_method},
+        FailedGames, Game, GameData, GameInfo, GameResult, GameState, GameTransitionInfo,
+        assets::{game_assets_ready, load_game_assets},
+        get_info, populate_game_pool,
+        pre_game::control_method::control_method,
     },
     layout,
+    localization::Localized,
     menus::MenuAssets,
     screens::Screen,
     theme::widget,
@@ -30,6 +34,12 @@ pub(super) fn plugin(app: &mut App) {
     app.load_resource::<PreGameAssets>();
     app.add_plugins((hint::plugin, control_method::plugin, thermometer::plugin));
     app.add_systems(OnEnter(GAME), spawn);
+    app.add_systems(
+        Update,
+        (start_game.run_if(next_game_assets_ready), skip_failed_game)
+            .in_set(AppSystems::Update)
+            .run_if(in_state(GAME).and(countdown_done)),
+    );
     app.init_resource::<PreGameState>();
 }
 
@@ -38,6 +48,8 @@ pub(super) fn plugin(app: &mut App) {
 pub struct PreGameState {
     pub start_time: Duration,
     pub info: GameInfo,
+    /// The countdown has finished, the next game starts once its assets are ready
+    pub countdown_done: bool,
 }
 
 impl PreGameState {
@@ -46,6 +58,7 @@ impl PreGameState {
     pub fn reset(&mut self, start_time: Duration, info: GameInfo) {
         self.start_time = start_time;
         self.info = info;
+        self.countdown_done = false;
     }
 }
 
@@ -88,13 +101,20 @@ fn image_from_result(
     }
 }
 
+fn result_text(result: Option<GameResult>) -> Localized {
+    match result {
+        Some(GameResult::Passsed) => Localized::new("pre_game.passed"),
+        Some(GameResult::Failed) => Localized::new("pre_game.failed"),
+        None => Localized::verbatim(""),
+    }
+}
+
 /// A system to spawn the example level
 pub fn spawn(
     mut commands: Commands,
     mut state: ResMut<PreGameState>,
     time: Res<Time>,
     game_state: Res<State<GameState>>,
-    control_assets: Res<ControlMethodAssets>,
     game_assets: Res<PreGameAssets>,
     background_assets: Res<BackgroundAssets>,
     data: Res<GameData>,
@@ -102,6 +122,7 @@ pub fn spawn(
 ) {
     if let GameState::PreGame(info) = game_state.get() {
         state.reset(time.elapsed(), info.next);
+        commands.queue(load_game_assets(info.next.kind));
 
         info!(
             "Fever grade: {} ({})",
@@ -136,15 +157,29 @@ pub fn spawn(
                         (
                             layout::center(),
                             children![(
-                                control_method(info.next.controls, &control_assets),
+                                control_method(info.next.controls, menu_assets.font.clone()),
                                 Visibility::Hidden,
                                 ShowAt::from_duration(time.elapsed() + Duration::from_millis(500))
                             )],
                         ),
+                        (
+                            layout::bottom_left(),
+                            // Says what the background shows, for players who can't tell it by color
+                            children![
+                                widget::label_with_shadow(
+                                    result_text(info.last),
+                                    menu_assets.font.clone(),
+                                ),
+                                widget::label_with_shadow(
+                                    info.reason.map_or(Localized::verbatim(""), Localized::new),
+                                    menu_assets.font.clone(),
+                                ),
+                            ],
+                        ),
                         (
                             layout::top_left(),
                             children![widget::header_with_color(
-                                format!("{}", data.round),
+                                Localized::verbatim(data.round.to_string()),
                                 color_u32(info.next.color),
                                 menu_assets.font.clone(),
                             )],
@@ -156,8 +191,19 @@ pub fn spawn(
     }
 }
 
-fn timed_out(
-    _event: On<TimedOut>,
+fn timed_out(_event: On<TimedOut>, mut state: ResMut<PreGameState>) {
+    state.countdown_done = true;
+}
+
+fn countdown_done(state: Res<PreGameState>) -> bool {
+    state.countdown_done
+}
+
+fn next_game_assets_ready(world: &World) -> bool {
+    game_assets_ready(world, world.resource::<PreGameState>().info.kind)
+}
+
+fn start_game(
     state: Res<PreGameState>,
     mut next_game_state: ResMut<NextState<GameState>>,
     mut next_game: ResMut<NextState<Game>>,
@@ -167,3 +213,51 @@ fn timed_out(
     next_game.set(kind);
     info!("Pre game completed. Starting {}", kind);
 }
+
+/// Replace the next game with another one if its assets failed to load
+fn skip_failed_game(
+    mut commands: Commands,
+    state: Res<PreGameState>,
+    scoped: Query<(Entity, &DespawnOnExit<Game>)>,
+    failed_games: Res<FailedGames>,
+    game_state: Res<State<GameState>>,
+    mut game_data: ResMut<GameData>,
+    mut next_game_state: ResMut<NextState<GameState>>,
+    mut next_game: ResMut<NextState<Game>>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    if !failed_games.0.contains(&state.info.kind) {
+        return;
+    }
+
+    if game_data.game_pool.is_empty() {
+        populate_game_pool(&mut game_data, &failed_games, Some(state.info.kind));
+    }
+    let Some(replacement) = game_data.game_pool.pop() else {
+        error!("No playable games left, all of them failed to load");
+        next_screen.set(Screen::PostGame);
+        return;
+    };
+
+    let (last, reason) = match game_state.get() {
+        GameState::PreGame(info) => (info.last, info.reason),
+        _ => (None, None),
+    };
+    info!(
+        "{} failed to load. Playing {} instead",
+        state.info.kind, replacement
+    );
+    // Re-enter the pre game so the countdown runs again while the replacement loads.
+    // Entering the state it is already in doesn't despawn anything, so clear it out here
+    for (entity, scope) in &scoped {
+        if scope.0 == GAME {
+            commands.entity(entity).try_despawn();
+        }
+    }
+    next_game.set(GAME);
+    next_game_state.set(GameState::PreGame(GameTransitionInfo {
+        next: get_info(replacement),
+        last,
+        reason,
+    }));
+}
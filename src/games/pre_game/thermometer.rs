@@ -1,13 +1,16 @@
-use bevy::{color::palettes::css, prelude::*};
+use bevy::prelude::*;
 
 use crate::{
     AppSystems, PausableSystems,
     asset_tracking::LoadResource,
-    controls::progress_bar::ProgressBar,
+    controls::progress_bar::{Fill, FillAnimation, ProgressBar},
     float::Floats,
-    games::{GameData, pre_game::GAME},
+    games::{GameData, balance::MAX_FEVER, pre_game::GAME},
     screens::Screen,
-    theme::widget,
+    theme::{
+        palette::{ColorMode, FEVER},
+        widget,
+    },
 };
 
 pub(super) fn plugin(app: &mut App) {
@@ -42,6 +45,10 @@ impl FromWorld for ThermometerAssets {
 #[derive(Debug, Component)]
 pub struct Thermometer;
 
+/// Pips showing how many more games can be failed before the fever peaks
+#[derive(Debug, Component)]
+struct Lives;
+
 fn spawn(mut commands: Commands, assets: Res<ThermometerAssets>) {
     commands.spawn((
         widget::ui_root("heath"),
@@ -49,43 +56,87 @@ fn spawn(mut commands: Commands, assets: Res<ThermometerAssets>) {
         DespawnOnExit(Screen::Gameplay),
         DespawnOnExit(GAME),
         Floats,
-        children![
-            (
-                Node {
-                    right: px(0),
-                    position_type: PositionType::Absolute,
-                    width: px(120.5),
-                    height: px(299.5),
-                    ..default()
-                },
-                ImageNode::new(assets.background.clone()),
-                Pickable::IGNORE,
-            ),
-            (
-                Node {
-                    right: px(0),
-                    position_type: PositionType::Absolute,
-                    width: px(120.5),
-                    height: px(299.5),
-                    ..default()
-                },
-                Thermometer,
-                ProgressBar {
-                    color: css::RED.into(),
-                    color_texture: assets.foreground.clone(),
-                    vertical: true,
-                    ..default()
-                },
-                Pickable::IGNORE,
-            )
-        ],
+        children![(
+            Node {
+                right: px(0),
+                position_type: PositionType::Absolute,
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                row_gap: px(10),
+                ..default()
+            },
+            Pickable::IGNORE,
+            children![
+                (
+                    Node {
+                        width: px(120.5),
+                        height: px(299.5),
+                        ..default()
+                    },
+                    Pickable::IGNORE,
+                    children![
+                        (
+                            Node {
+                                position_type: PositionType::Absolute,
+                                width: percent(100),
+                                height: percent(100),
+                                ..default()
+                            },
+                            ImageNode::new(assets.background.clone()),
+                            Pickable::IGNORE,
+                        ),
+                        (
+                            Node {
+                                position_type: PositionType::Absolute,
+                                width: percent(100),
+                                height: percent(100),
+                                ..default()
+                            },
+                            Thermometer,
+                            ProgressBar {
+                                color: FEVER,
+                                color_texture: assets.foreground.clone(),
+                                fill: Fill::Vertical,
+                                animation: Some(FillAnimation::default()),
+                                ..default()
+                            },
+                            Pickable::IGNORE,
+                        ),
+                    ],
+                ),
+                (
+                    Node {
+                        width: px(100),
+                        height: px(16),
+                        ..default()
+                    },
+                    Lives,
+                    ProgressBar {
+                        color: FEVER,
+                        animation: Some(FillAnimation::default()),
+                        ..ProgressBar::pips(MAX_FEVER as u32, MAX_FEVER as u32)
+                    },
+                    Pickable::IGNORE,
+                ),
+            ],
+        )],
     ));
 }
 
-fn update(mut query: Query<&mut ProgressBar, With<Thermometer>>, data: Res<GameData>) {
+fn update(
+    mut query: Query<&mut ProgressBar, (With<Thermometer>, Without<Lives>)>,
+    mut lives_query: Query<&mut ProgressBar, With<Lives>>,
+    data: Res<GameData>,
+    color_mode: Res<ColorMode>,
+) {
     const MIN_THERMOMETER: f32 = 0.34;
     for mut progress_bar in query.iter_mut() {
+        progress_bar.color = color_mode.adjust(FEVER);
         progress_bar.progress =
             MIN_THERMOMETER + (data.fever_grade_nominal() * (1.0 - MIN_THERMOMETER));
     }
+    for mut progress_bar in lives_query.iter_mut() {
+        progress_bar.color = color_mode.adjust(FEVER);
+        progress_bar.progress = 1.0 - data.fever_grade_nominal();
+    }
 }
@@ -0,0 +1,27 @@
+use std::time::Duration;
+
+pub const GAME_DURATION: Duration = Duration::from_secs(5);
+/// Height of the world shown by the game camera
+pub const VIEW_HEIGHT: f32 = 360.0;
+
+/// Presses per second needed at level 0 to fill the balloon in [`FILL_TIME`]
+pub const TARGET_RATE: f32 = 6.0;
+/// Scales the target rate for each level, up to [`MAX_TARGET_RATE`]
+pub const LEVEL_MULTIPLIER: f32 = 1.1;
+pub const MAX_TARGET_RATE: f32 = 10.0;
+/// Presses are counted over this much time to measure the rate
+pub const RATE_WINDOW: Duration = Duration::from_secs(1);
+
+/// Seconds to fill the balloon at exactly the target rate, before leaking
+pub const FILL_TIME: f32 = 2.5;
+/// Part of the balloon that leaks out every second
+pub const LEAK: f32 = 0.1;
+
+pub const BALLOON_RADIUS: f32 = 60.0;
+/// Scale of the balloon when empty and when full
+pub const MIN_BALLOON_SCALE: f32 = 0.3;
+pub const MAX_BALLOON_SCALE: f32 = 1.6;
+
+/// Rumble added for a press with a full balloon, less the emptier it is
+pub const PRESS_RUMBLE: f32 = 0.15;
+pub const POP_TRAUMA: f32 = 0.6;
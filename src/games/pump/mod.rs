@@ -0,0 +1,289 @@
+use std::{collections::VecDeque, time::Duration};
+
+use bevy::{
+    app::Propagate, camera::ScalingMode, input::common_conditions::input_just_pressed, prelude::*,
+};
+
+use crate::{
+    AppSystems, PausableSystems,
+    asset_manifest::{AssetManifest, FromManifest, ManifestError},
+    audio::sound_effect,
+    color::color_u32,
+    controls::progress_bar::{FillAnimation, Pattern, ProgressBar},
+    games::{
+        Game, GameControlMethod, GameData, GameInfo, GameResult, LoadGameResource, NextGame,
+        camera::{
+            self,
+            shake::{AddTrauma, CameraShakeConfig},
+        },
+    },
+    screens::Screen,
+    theme::widget,
+    timeout::{TimedOut, Timeout, TimeoutBar, WARNING_THRESHOLDS},
+};
+
+mod balance;
+
+const GAME: Game = Game::Pump;
+
+pub(super) fn plugin(app: &mut App) {
+    // Register our assets to be loaded when the application is loading
+    app.load_game_manifest::<PumpAssets>(GAME);
+
+    // Register our spawn system to be triggered when this game is selected
+    app.add_systems(OnEnter(GAME), spawn);
+
+    // Register all systems that are to be run when this game is active
+    app.add_systems(
+        Update,
+        (
+            pump.run_if(input_just_pressed(KeyCode::Space)),
+            update,
+            update_balloon,
+            update_bar,
+        )
+            .chain()
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems)
+            .run_if(in_state(GAME)),
+    );
+
+    // Register a basic data structure that we can use to track data for this game
+    app.init_resource::<PumpState>();
+}
+
+pub const fn get_info() -> GameInfo {
+    GameInfo {
+        kind: GAME,
+        controls: GameControlMethod::Space,
+        hint: "hint.pump",
+        color: 0xEE4488FF,
+        pattern: Pattern::Stripes,
+    }
+}
+
+/// All data representing the current state of this game
+#[derive(Debug, Default, Clone, Resource)]
+pub struct PumpState {
+    pub start_time: Duration,
+    /// How full the balloon is, from 0 to 1
+    pub air: f32,
+    /// Presses per second needed to fill the balloon in time
+    pub target_rate: f32,
+    /// When each press in the last [`balance::RATE_WINDOW`] happened
+    pub presses: VecDeque<Duration>,
+    pub popped: bool,
+}
+
+impl PumpState {
+    /// Called when starting this game to make sure the data is reset
+    /// Assuming that is what we want.
+    pub fn reset(&mut self, start_time: Duration, level: usize) {
+        let level_multiplier = f32::powi(balance::LEVEL_MULTIPLIER, level as i32);
+        self.start_time = start_time;
+        self.air = 0.0;
+        self.target_rate = (balance::TARGET_RATE * level_multiplier).min(balance::MAX_TARGET_RATE);
+        self.presses.clear();
+        self.popped = false;
+    }
+
+    /// Presses per second over the last [`balance::RATE_WINDOW`]
+    pub fn rate(&self) -> f32 {
+        self.presses.len() as f32 / balance::RATE_WINDOW.as_secs_f32()
+    }
+}
+
+/// Used to track all assets for this game
+#[derive(Resource, Asset, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct PumpAssets {
+    #[dependency]
+    pump: Handle<AudioSource>,
+    #[dependency]
+    pop: Handle<AudioSource>,
+    #[dependency]
+    bgm: Handle<AudioSource>,
+}
+
+impl FromManifest for PumpAssets {
+    const PATH: &'static str = "games/pump/pump.manifest";
+
+    /// Pick all assets we want for this game out of the manifest
+    fn from_manifest(manifest: &AssetManifest) -> Result<Self, ManifestError> {
+        Ok(Self {
+            pump: manifest.sound("pump")?,
+            pop: manifest.sound("pop")?,
+            bgm: manifest.sound("bgm")?,
+        })
+    }
+}
+
+/// Grows with the air pumped into it
+#[derive(Debug, Component)]
+struct Balloon;
+
+/// Shows how full the balloon is
+#[derive(Debug, Component)]
+struct AirBar;
+
+/// A system to spawn the balloon
+pub fn spawn(
+    mut commands: Commands,
+    assets: Res<PumpAssets>,
+    gamedata: Res<GameData>,
+    mut state: ResMut<PumpState>,
+    time: Res<Time>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    state.reset(time.elapsed(), gamedata.level);
+
+    commands.spawn((
+        DespawnOnExit(GAME),             // When exiting this game despawn this entity
+        DespawnOnExit(Screen::Gameplay), // When exiting the top level game despawn this entity
+        Camera2d,
+        CameraShakeConfig::default(),
+        Camera {
+            order: -1,
+            clear_color: ClearColorConfig::None,
+            ..default()
+        },
+        Projection::from(OrthographicProjection {
+            scaling_mode: ScalingMode::FixedVertical {
+                viewport_height: balance::VIEW_HEIGHT,
+            },
+            ..OrthographicProjection::default_2d()
+        }),
+        camera::RENDERLAYER_GAME,
+    ));
+
+    commands.spawn((
+        Name::new("pump_level"),
+        Transform::default(),
+        Visibility::default(),
+        DespawnOnExit(GAME), // When exiting this game despawn this entity
+        DespawnOnExit(Screen::Gameplay), // When exiting the top level game despawn this entity
+        Propagate(camera::RENDERLAYER_GAME),
+        AudioPlayer(assets.bgm.clone()),
+        children![
+            (
+                Name::new("string"),
+                Transform::from_xyz(0.0, -85.0, 1.0),
+                Sprite::from_color(Color::srgb(0.9, 0.9, 0.9), Vec2::new(2.0, 170.0)),
+            ),
+            (
+                Name::new("balloon"),
+                Balloon,
+                Transform::from_xyz(0.0, 0.0, 2.0)
+                    .with_scale(Vec2::splat(balance::MIN_BALLOON_SCALE).extend(1.0)),
+                Mesh2d(meshes.add(Circle::new(balance::BALLOON_RADIUS))),
+                MeshMaterial2d(materials.add(color_u32(get_info().color))),
+            ),
+        ],
+    ));
+
+    commands
+        .spawn((
+            widget::ui_root("pump_ui"),
+            DespawnOnExit(GAME), // When exiting this game despawn this entity
+            DespawnOnExit(Screen::Gameplay), // When exiting the top level game despawn this entity
+            Timeout::new(balance::GAME_DURATION),
+            children![
+                (
+                    Name::new("air_bar"),
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: px(20),
+                        width: px(300),
+                        height: px(30),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+                    AirBar,
+                    ProgressBar {
+                        color: color_u32(get_info().color),
+                        pattern: get_info().pattern,
+                        animation: Some(FillAnimation {
+                            duration: Duration::from_millis(100),
+                            ..default()
+                        }),
+                        ..default()
+                    },
+                ),
+                TimeoutBar::from_foreground_color(color_u32(get_info().color))
+                    .with_pattern(get_info().pattern)
+                    .with_thresholds(WARNING_THRESHOLDS)
+            ],
+        ))
+        .observe(timed_out);
+}
+
+fn timed_out(_event: On<TimedOut>, mut tx: MessageWriter<NextGame>, state: Res<PumpState>) {
+    if !state.popped {
+        tx.write(NextGame::from_result(GameResult::Failed));
+        info!("timeout - next game");
+    }
+}
+
+/// Count a press, rumbling harder the fuller the balloon gets
+fn pump(
+    mut commands: Commands,
+    mut state: ResMut<PumpState>,
+    assets: Res<PumpAssets>,
+    time: Res<Time>,
+) {
+    if state.popped {
+        return;
+    }
+    state.presses.push_back(time.elapsed());
+    commands.spawn(sound_effect(assets.pump.clone()));
+    commands.trigger(AddTrauma::rumble(balance::PRESS_RUMBLE * state.air));
+}
+
+/// Fill the balloon at the measured press rate against the target, and pass once it pops
+fn update(
+    mut commands: Commands,
+    mut state: ResMut<PumpState>,
+    assets: Res<PumpAssets>,
+    time: Res<Time>,
+    balloon: Single<Entity, With<Balloon>>,
+    mut tx: MessageWriter<NextGame>,
+) {
+    if state.popped {
+        return;
+    }
+
+    let now = time.elapsed();
+    while state
+        .presses
+        .front()
+        .is_some_and(|press| now - *press > balance::RATE_WINDOW)
+    {
+        state.presses.pop_front();
+    }
+
+    let filled = state.rate() / state.target_rate / balance::FILL_TIME;
+    state.air = (state.air + (filled - balance::LEAK) * time.delta_secs()).clamp(0.0, 1.0);
+
+    if state.air >= 1.0 {
+        state.popped = true;
+        commands.entity(*balloon).despawn();
+        commands.spawn(sound_effect(assets.pop.clone()));
+        commands.trigger(AddTrauma::new(balance::POP_TRAUMA));
+        tx.write(NextGame::from_result(GameResult::Passsed));
+        info!("popped - next game");
+    }
+}
+
+fn update_balloon(state: Res<PumpState>, mut query: Query<&mut Transform, With<Balloon>>) {
+    let scale = balance::MIN_BALLOON_SCALE.lerp(balance::MAX_BALLOON_SCALE, state.air);
+    for mut transform in &mut query {
+        transform.scale = Vec2::splat(scale).extend(1.0);
+    }
+}
+
+fn update_bar(state: Res<PumpState>, mut query: Query<&mut ProgressBar, With<AirBar>>) {
+    for mut progress_bar in &mut query {
+        progress_bar.progress = state.air;
+    }
+}
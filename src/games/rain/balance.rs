@@ -1,4 +1,4 @@
-use std::time::Duration;
+use std::{ops::Range, time::Duration};
 
 pub const GAME_DURATION: Duration = Duration::from_secs(5);
 
@@ -6,7 +6,31 @@ pub const GAME_DURATION: Duration = Duration::from_secs(5);
 pub const MAX_WET_TIME: f32 = 1.0;
 // Distance from the center of the umbrella before counting as not in shelter
 pub const SHELTER_THRESHOLD: f32 = 22.0;
+// Height of the shade under an umbrella, about the distance down to the duck
+pub const SHELTER_HEIGHT: f32 = 48.0;
 
 pub const UMBRELLA_MAX_VELOCITY: f32 = 30.0;
 pub const PLAYER_MOVEMENT_SPEED: f32 = 42.0;
 pub const LEVEL_MULTIPLIER: f32 = 1.3;
+
+// Levels from which wind, puddles and a second umbrella join in
+pub const WIND_LEVEL: usize = 1;
+pub const PUDDLE_LEVEL: usize = 2;
+pub const SECOND_UMBRELLA_LEVEL: usize = 3;
+
+// Furthest a gust shifts the sheltered zone, in pixels
+pub const MAX_WIND_SHIFT: f32 = 16.0;
+// Pixels per second the sheltered zone moves as the wind changes
+pub const WIND_CHANGE_SPEED: f32 = 24.0;
+// Seconds between changes of wind
+pub const GUST_INTERVAL: Range<f32> = 0.8..1.6;
+
+pub const PUDDLES_PER_LEVEL: usize = 1;
+pub const MAX_PUDDLES: usize = 3;
+pub const PUDDLE_WIDTH: f32 = 20.0;
+// Speed multiplier of the duck while wading through a puddle
+pub const PUDDLE_SLOWDOWN: f32 = 0.4;
+
+// Seconds the second umbrella stays open and closed
+pub const FLAKY_OPEN_TIME: Range<f32> = 1.0..2.0;
+pub const FLAKY_CLOSED_TIME: Range<f32> = 0.5..1.0;
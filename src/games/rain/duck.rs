@@ -8,14 +8,7 @@ use crate::audio::sound_effect;
 use crate::games::rain::RainAssets;
 use rand::prelude::*;
 
-pub fn duck(
-    assets: &RainAssets,
-    texture_atlas_layouts: &mut Assets<TextureAtlasLayout>,
-    max_speed: f32,
-) -> impl Bundle {
-    let layout = TextureAtlasLayout::from_grid(UVec2 { x: 32, y: 32 }, 3, 3, None, None);
-    let texture_atlas_layout = texture_atlas_layouts.add(layout);
-
+pub fn duck(assets: &RainAssets, max_speed: f32) -> impl Bundle {
     let player_anim = PlayerAnimation::new();
 
     (
@@ -24,7 +17,7 @@ pub fn duck(
         Sprite::from_atlas_image(
             assets.duck.clone(),
             TextureAtlas {
-                layout: texture_atlas_layout,
+                layout: assets.duck_layout.clone(),
                 index: player_anim.get_atlas_index(),
             },
         ),
@@ -4,26 +4,30 @@ use bevy::{
     app::Propagate,
     camera::RenderTarget,
     camera::ScalingMode,
-    image::{ImageLoaderSettings, ImageSampler},
+    image::ImageSampler,
     prelude::*,
     render::render_resource::{
         Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
     },
+    sprite::Anchor,
 };
 
+use rand::Rng;
+
 use crate::{
     AppSystems, PausableSystems,
-    asset_tracking::LoadResource,
+    asset_manifest::{AssetManifest, FromManifest, ManifestError},
     color::color_u32,
+    controls::progress_bar::Pattern,
     games::{
-        Game, GameControlMethod, GameInfo, GameResult, NextGame,
+        Game, GameControlMethod, GameInfo, GameResult, LoadGameResource, NextGame,
         camera::{self, shake::CameraShakeConfig},
-        rain::umbrella::Umbrella,
+        rain::umbrella::{Flaky, Umbrella},
     },
     movement::TopDownMovementController,
     screens::Screen,
     theme::widget,
-    timeout::{TimedOut, Timeout, TimeoutBar},
+    timeout::{TimedOut, Timeout, TimeoutBar, WARNING_THRESHOLDS},
 };
 
 use crate::animation::{AnimationIndices, AnimationTimer};
@@ -37,11 +41,20 @@ mod umbrella;
 const GAME: Game = Game::Rain;
 
 pub(super) fn plugin(app: &mut App) {
-    app.load_resource::<RainAssets>();
+    app.load_game_manifest::<RainAssets>(GAME);
     app.add_systems(OnEnter(GAME), (spawn, spawn_camera));
     app.add_systems(
         Update,
-        (update, umbrella::update)
+        (
+            update,
+            update_wind,
+            update_rain,
+            update_puddles,
+            update_meter,
+            umbrella::update,
+            umbrella::update_flaky,
+            umbrella::update_shelter,
+        )
             .in_set(AppSystems::Update)
             .in_set(PausableSystems)
             .run_if(in_state(GAME)),
@@ -63,8 +76,9 @@ pub const fn get_info() -> GameInfo {
     GameInfo {
         kind: GAME,
         controls: GameControlMethod::Wasd,
-        hint: "Shelter",
+        hint: "hint.shelter",
         color: 0xFFFFFFFF,
+        pattern: Pattern::Waves,
     }
 }
 
@@ -73,21 +87,49 @@ pub const fn get_info() -> GameInfo {
 pub struct RainState {
     pub start_time: Duration,
     pub wetness: f32,
+    pub level: usize,
+    /// How far the wind shifts the sheltered zone along x, in pixels
+    pub wind: f32,
+    /// The shift the wind is blowing towards
+    pub wind_target: f32,
+    /// Seconds since startup when the wind changes next
+    pub next_gust: f32,
 }
 
 impl RainState {
-    pub fn reset(&mut self, start_time: Duration) {
+    pub fn reset(&mut self, start_time: Duration, level: usize) {
         self.start_time = start_time;
         self.wetness = 0.0;
+        self.level = level;
+        self.wind = 0.0;
+        self.wind_target = 0.0;
+        self.next_gust = start_time.as_secs_f32();
     }
 }
 
+/// The falling rain, slanted by the wind like the shade under the umbrellas
+#[derive(Component)]
+struct RainLayer;
+
+/// Slows the duck down while it wades through
+#[derive(Component)]
+struct Puddle;
+
+/// Fill of the wetness meter, scaled by the wetness
+#[derive(Component)]
+struct WetnessMeter;
+
+const METER_SIZE: Vec2 = Vec2::new(40.0, 4.0);
+
 /// Used to track all assets for this game
 #[derive(Resource, Asset, Clone, Reflect)]
 #[reflect(Resource)]
 pub struct RainAssets {
     #[dependency]
     pub duck: Handle<Image>,
+    /// Shared by `duck` and `duck_wet`
+    #[dependency]
+    pub duck_layout: Handle<TextureAtlasLayout>,
     #[dependency]
     pub duck_wet: Handle<Image>,
     #[dependency]
@@ -97,55 +139,32 @@ pub struct RainAssets {
     #[dependency]
     pub rain: Handle<Image>,
     #[dependency]
+    pub rain_layout: Handle<TextureAtlasLayout>,
+    #[dependency]
     pub ground: Handle<Image>,
     #[dependency]
+    pub ground_layout: Handle<TextureAtlasLayout>,
+    #[dependency]
     pub bgm: Handle<AudioSource>,
 }
 
-impl FromWorld for RainAssets {
-    /// Load all assets we want for this game
-    fn from_world(world: &mut World) -> Self {
-        let assets = world.resource::<AssetServer>();
-        Self {
-            duck: assets.load_with_settings(
-                "games/rain/duck_anim.png",
-                |settings: &mut ImageLoaderSettings| {
-                    settings.sampler = ImageSampler::nearest();
-                },
-            ),
-            duck_wet: assets.load_with_settings(
-                "games/rain/duck_wet_anim.png",
-                |settings: &mut ImageLoaderSettings| {
-                    settings.sampler = ImageSampler::nearest();
-                },
-            ),
-            steps: vec![
-                assets.load("games/rain/steps-001.ogg"),
-                assets.load("games/rain/steps-002.ogg"),
-                assets.load("games/rain/steps-003.ogg"),
-                assets.load("games/rain/steps-004.ogg"),
-                assets.load("games/rain/steps-005.ogg"),
-            ],
-            umbrella: assets.load_with_settings(
-                "games/rain/umbrella.png",
-                |settings: &mut ImageLoaderSettings| {
-                    settings.sampler = ImageSampler::nearest();
-                },
-            ),
-            rain: assets.load_with_settings(
-                "games/rain/rain.png",
-                |settings: &mut ImageLoaderSettings| {
-                    settings.sampler = ImageSampler::nearest();
-                },
-            ),
-            ground: assets.load_with_settings(
-                "games/rain/droplets.png",
-                |settings: &mut ImageLoaderSettings| {
-                    settings.sampler = ImageSampler::nearest();
-                },
-            ),
-            bgm: assets.load("games/rain/bgm.ogg"),
-        }
+impl FromManifest for RainAssets {
+    const PATH: &'static str = "games/rain/rain.manifest";
+
+    /// Pick all assets we want for this game out of the manifest
+    fn from_manifest(manifest: &AssetManifest) -> Result<Self, ManifestError> {
+        Ok(Self {
+            duck: manifest.image("duck")?,
+            duck_layout: manifest.layout("duck")?,
+            duck_wet: manifest.image("duck_wet")?,
+            steps: manifest.sounds("steps")?,
+            umbrella: manifest.image("umbrella")?,
+            rain: manifest.image("rain")?,
+            rain_layout: manifest.layout("rain")?,
+            ground: manifest.image("ground")?,
+            ground_layout: manifest.layout("ground")?,
+            bgm: manifest.sound("bgm")?,
+        })
     }
 }
 
@@ -240,42 +259,55 @@ pub fn spawn(
     mut state: ResMut<RainState>,
     time: Res<Time>,
     gamedata: Res<GameData>,
-    mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
 ) {
-    state.reset(time.elapsed());
+    state.reset(time.elapsed(), gamedata.level);
 
     let level_multiplier = f32::powi(balance::LEVEL_MULTIPLIER, gamedata.level as i32);
     info!("level mult = {}", level_multiplier);
+    let mut rng = rand::rng();
 
-    let layout = TextureAtlasLayout::from_grid(UVec2 { x: 200, y: 120 }, 2, 2, None, None);
-    let texture_atlas_layout = texture_atlas_layouts.add(layout);
     let level = commands
         .spawn((
             Name::new("background"),
             Transform::default(),
             Visibility::default(),
-            Sprite::from_atlas_image(
-                assets.rain.clone(),
-                TextureAtlas {
-                    layout: texture_atlas_layout,
-                    index: 0,
-                },
-            ),
-            AnimationIndices { first: 0, last: 3 },
-            AnimationTimer(Timer::from_seconds(0.1, TimerMode::Repeating)),
             children![
-                umbrella::umbrella(&assets, balance::UMBRELLA_MAX_VELOCITY * level_multiplier),
-                duck::duck(
+                (
+                    Name::new("rain"),
+                    RainLayer,
+                    Transform::default(),
+                    Sprite::from_atlas_image(
+                        assets.rain.clone(),
+                        TextureAtlas {
+                            layout: assets.rain_layout.clone(),
+                            index: 0,
+                        },
+                    ),
+                    AnimationIndices { first: 0, last: 3 },
+                    AnimationTimer(Timer::from_seconds(0.1, TimerMode::Repeating)),
+                ),
+                umbrella::umbrella(
                     &assets,
-                    &mut texture_atlas_layouts,
-                    balance::PLAYER_MOVEMENT_SPEED * level_multiplier
-                )
+                    balance::UMBRELLA_MAX_VELOCITY * level_multiplier,
+                    0.0
+                ),
+                duck::duck(&assets, balance::PLAYER_MOVEMENT_SPEED * level_multiplier)
             ],
         ))
         .id();
 
-    let layout = TextureAtlasLayout::from_grid(UVec2 { x: 200, y: 14 }, 1, 5, None, None);
-    let texture_atlas_layout = texture_atlas_layouts.add(layout);
+    if gamedata.level >= balance::SECOND_UMBRELLA_LEVEL {
+        let first_toggle = time.elapsed_secs() + rng.random_range(balance::FLAKY_OPEN_TIME);
+        commands.entity(level).with_child((
+            umbrella::umbrella(
+                &assets,
+                balance::UMBRELLA_MAX_VELOCITY * level_multiplier,
+                rng.random_range(-60.0..60.0),
+            ),
+            Flaky::new(first_toggle),
+        ));
+    }
+
     let ground = commands
         .spawn((
             Name::new("ground"),
@@ -284,7 +316,7 @@ pub fn spawn(
             Sprite::from_atlas_image(
                 assets.ground.clone(),
                 TextureAtlas {
-                    layout: texture_atlas_layout,
+                    layout: assets.ground_layout.clone(),
                     index: 0,
                 },
             ),
@@ -293,6 +325,48 @@ pub fn spawn(
         ))
         .id();
 
+    if gamedata.level >= balance::PUDDLE_LEVEL {
+        let puddles = ((gamedata.level - balance::PUDDLE_LEVEL + 1) * balance::PUDDLES_PER_LEVEL)
+            .min(balance::MAX_PUDDLES);
+        commands.entity(ground).with_children(|parent| {
+            for _ in 0..puddles {
+                parent.spawn((
+                    Name::new("puddle"),
+                    Puddle,
+                    Transform::from_xyz(rng.random_range(-70.0..70.0), 6.0, 1.0),
+                    Sprite {
+                        color: Color::srgba(0.2, 0.3, 0.6, 0.8),
+                        custom_size: Some(Vec2::new(balance::PUDDLE_WIDTH, 3.0)),
+                        ..default()
+                    },
+                ));
+            }
+        });
+    }
+
+    let meter = commands
+        .spawn((
+            Name::new("wetness_meter"),
+            Transform::from_xyz(-95.0, 50.0, 30.0),
+            Sprite {
+                color: Color::srgba(0.0, 0.0, 0.0, 0.5),
+                custom_size: Some(METER_SIZE),
+                ..default()
+            },
+            Anchor::CENTER_LEFT,
+            children![(
+                WetnessMeter,
+                Transform::from_xyz(0.0, 0.0, 1.0).with_scale(Vec3::new(0.0, 1.0, 1.0)),
+                Sprite {
+                    color: Color::srgb(0.3, 0.6, 1.0),
+                    custom_size: Some(METER_SIZE),
+                    ..default()
+                },
+                Anchor::CENTER_LEFT,
+            )],
+        ))
+        .id();
+
     commands
         .spawn((
             Name::new("root"),
@@ -303,7 +377,7 @@ pub fn spawn(
             Propagate(camera::RENDERLAYER_GAME),
             AudioPlayer(assets.bgm.clone()),
         ))
-        .add_children(&[level, ground]);
+        .add_children(&[level, ground, meter]);
 
     commands
         .spawn((
@@ -311,9 +385,11 @@ pub fn spawn(
             DespawnOnExit(GAME), // When exiting this game despawn this entity
             DespawnOnExit(Screen::Gameplay), // When exiting the top level game despawn this entity
             Timeout::new(balance::GAME_DURATION),
-            children![TimeoutBar::from_foreground_color(color_u32(
-                get_info().color
-            ))],
+            children![
+                TimeoutBar::from_foreground_color(color_u32(get_info().color))
+                    .with_pattern(get_info().pattern)
+                    .with_thresholds(WARNING_THRESHOLDS)
+            ],
         ))
         .observe(timed_out);
 }
@@ -333,12 +409,16 @@ fn update(
     assets: Res<RainAssets>,
     mut state: ResMut<RainState>,
     player: Single<(&Transform, &mut Sprite), With<TopDownMovementController>>,
-    umbrella: Single<&Transform, With<Umbrella>>,
+    umbrellas: Query<(&Transform, &Umbrella)>,
 ) {
     let (player_transform, mut sprite) = player.into_inner();
 
-    let umbrella_dist = f32::abs(player_transform.translation.x - umbrella.translation.x);
-    let sheltered = umbrella_dist < balance::SHELTER_THRESHOLD;
+    // The wind blows the rain and so the sheltered zone sideways
+    let sheltered = umbrellas.iter().any(|(transform, umbrella)| {
+        let umbrella_dist =
+            f32::abs(player_transform.translation.x - (transform.translation.x + state.wind));
+        umbrella.open && umbrella_dist < balance::SHELTER_THRESHOLD
+    });
 
     let prev_wet = state.wetness < 1.0;
 
@@ -350,3 +430,53 @@ fn update(
         }
     }
 }
+
+/// Pick a new gust now and then once the level is windy, and blow towards it
+fn update_wind(time: Res<Time>, mut state: ResMut<RainState>) {
+    if state.level < balance::WIND_LEVEL {
+        return;
+    }
+
+    if time.elapsed_secs() > state.next_gust {
+        let mut rng = rand::rng();
+        state.next_gust = time.elapsed_secs() + rng.random_range(balance::GUST_INTERVAL);
+        state.wind_target = rng.random_range(-balance::MAX_WIND_SHIFT..balance::MAX_WIND_SHIFT);
+    }
+
+    let step = balance::WIND_CHANGE_SPEED * time.delta_secs();
+    state.wind += (state.wind_target - state.wind).clamp(-step, step);
+}
+
+/// Slant the rain with the wind, scaled up just enough that its edges stay off screen
+fn update_rain(state: Res<RainState>, mut query: Query<&mut Transform, With<RainLayer>>) {
+    let angle = f32::atan2(state.wind, balance::SHELTER_HEIGHT);
+    let (sin, cos) = angle.abs().sin_cos();
+    let (width, height) = (RES_WIDTH as f32, RES_HEIGHT as f32);
+    let scale = f32::max(cos + sin * height / width, cos + sin * width / height);
+    for mut transform in &mut query {
+        transform.rotation = Quat::from_rotation_z(angle);
+        transform.scale = Vec3::new(scale, scale, 1.0);
+    }
+}
+
+fn update_puddles(
+    player: Single<(&GlobalTransform, &mut TopDownMovementController)>,
+    puddles: Query<&GlobalTransform, With<Puddle>>,
+) {
+    let (player_transform, mut controller) = player.into_inner();
+    let wading = puddles.iter().any(|puddle| {
+        f32::abs(player_transform.translation().x - puddle.translation().x)
+            < balance::PUDDLE_WIDTH / 2.0
+    });
+    controller.speed_multiplier = if wading {
+        balance::PUDDLE_SLOWDOWN
+    } else {
+        1.0
+    };
+}
+
+fn update_meter(state: Res<RainState>, mut query: Query<&mut Transform, With<WetnessMeter>>) {
+    for mut transform in &mut query {
+        transform.scale.x = state.wetness.clamp(0.0, 1.0);
+    }
+}
@@ -1,7 +1,7 @@
 use bevy::{prelude::*, sprite::Anchor};
 use rand::Rng;
 
-use crate::games::rain::RainAssets;
+use crate::games::rain::{RainAssets, RainState, balance};
 
 #[derive(Debug, Default, Component)]
 pub struct RandomMover {
@@ -11,12 +11,33 @@ pub struct RandomMover {
 }
 
 #[derive(Component)]
-pub struct Umbrella;
+pub struct Umbrella {
+    /// Closed umbrellas give no shelter
+    pub open: bool,
+}
+
+/// An umbrella that closes and opens again at random
+#[derive(Debug, Default, Component)]
+pub struct Flaky {
+    next_toggle: f32,
+}
+
+impl Flaky {
+    pub fn new(first_toggle: f32) -> Self {
+        Self {
+            next_toggle: first_toggle,
+        }
+    }
+}
+
+/// The shade under an umbrella, slanted by the wind
+#[derive(Component)]
+pub struct Shelter;
 
-pub fn umbrella(assets: &RainAssets, max_speed: f32) -> impl Bundle {
+pub fn umbrella(assets: &RainAssets, max_speed: f32, x: f32) -> impl Bundle {
     (
-        Umbrella,
-        Transform::from_xyz(0.0, -0.5, 10.0),
+        Umbrella { open: true },
+        Transform::from_xyz(x, -0.5, 10.0),
         Visibility::default(),
         Sprite::from_image(assets.umbrella.clone()),
         RandomMover {
@@ -25,10 +46,11 @@ pub fn umbrella(assets: &RainAssets, max_speed: f32) -> impl Bundle {
             end_time: 0.0,
         },
         children![(
+            Shelter,
             Transform::from_xyz(0.0, 4.0, -1.0),
             Sprite {
                 color: Color::BLACK,
-                custom_size: Some(Vec2::new(43.0, 48.0)),
+                custom_size: Some(Vec2::new(43.0, balance::SHELTER_HEIGHT)),
                 ..default()
             },
             Anchor::TOP_CENTER
@@ -56,3 +78,36 @@ pub fn update(time: Res<Time>, mut query: Query<(&mut RandomMover, &mut Transfor
         }
     }
 }
+
+/// Close and reopen [`Flaky`] umbrellas at random
+pub fn update_flaky(
+    time: Res<Time>,
+    mut query: Query<(&mut Flaky, &mut Umbrella, &mut Visibility)>,
+) {
+    let mut rng = rand::rng();
+    for (mut flaky, mut umbrella, mut visibility) in &mut query {
+        if time.elapsed_secs() < flaky.next_toggle {
+            continue;
+        }
+        umbrella.open = !umbrella.open;
+        *visibility = if umbrella.open {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        flaky.next_toggle = time.elapsed_secs()
+            + if umbrella.open {
+                rng.random_range(balance::FLAKY_OPEN_TIME)
+            } else {
+                rng.random_range(balance::FLAKY_CLOSED_TIME)
+            };
+    }
+}
+
+/// Slant the shade under each umbrella with the wind
+pub fn update_shelter(state: Res<RainState>, mut query: Query<&mut Transform, With<Shelter>>) {
+    let angle = f32::atan2(state.wind, balance::SHELTER_HEIGHT);
+    for mut transform in &mut query {
+        transform.rotation = Quat::from_rotation_z(angle);
+    }
+}
@@ -0,0 +1,11 @@
+use std::time::Duration;
+
+pub const GAME_DURATION: Duration = Duration::from_secs(5);
+/// Word lists in the string tables, `typing.words.0` has the shortest words.
+/// Each level moves one tier up until the last.
+pub const WORD_TIERS: usize = 3;
+/// Typed when the string tables have no words for the tier
+pub const FALLBACK_WORD: &str = "duck";
+pub const WRONG_KEY_TRAUMA: f32 = 0.3;
+/// Height of the world shown by the game camera, fits the background
+pub const VIEW_HEIGHT: f32 = 225.0;
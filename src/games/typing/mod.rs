@@ -0,0 +1,300 @@
+//! Type the word shown before the time runs out.
+//!
+//! Words are picked from the string table of the current language, see [`crate::localization`],
+//! under `typing.words.<tier>` as a space separated list. Later levels use longer words.
+
+use std::time::Duration;
+
+use bevy::{
+    app::Propagate,
+    camera::ScalingMode,
+    input::{
+        ButtonState,
+        keyboard::{Key, KeyboardInput},
+    },
+    prelude::*,
+};
+use rand::seq::IndexedRandom;
+
+use crate::{
+    AppSystems, PausableSystems,
+    asset_manifest::{AssetManifest, FromManifest, ManifestError},
+    audio::sound_effect,
+    color::color_u32,
+    controls::progress_bar::{Fill, Pattern},
+    games::{
+        Game, GameControlMethod, GameData, GameInfo, GameResult, LoadGameResource, NextGame,
+        camera::{
+            self,
+            shake::{AddTrauma, CameraShakeConfig},
+        },
+    },
+    localization::Locale,
+    menus::MenuAssets,
+    screens::Screen,
+    theme::{
+        palette::{BaseTextColor, HEADER_TEXT, TYPED_TEXT},
+        widget,
+    },
+    timeout::{TimedOut, Timeout, TimeoutBar, WARNING_THRESHOLDS},
+};
+
+mod balance;
+
+const GAME: Game = Game::Typing;
+
+pub(super) fn plugin(app: &mut App) {
+    // Register our assets to be loaded when the application is loading
+    app.load_game_manifest::<TypingAssets>(GAME);
+
+    // Register our spawn system to be triggered when this game is selected
+    app.add_systems(OnEnter(GAME), spawn);
+
+    // Register all systems that are to be run when this game is active
+    app.add_systems(
+        Update,
+        (update, update_word)
+            .chain()
+            .in_set(AppSystems::Update)
+            .in_set(PausableSystems)
+            .run_if(in_state(GAME)),
+    );
+
+    // Register a basic data structure that we can use to track data for this game
+    app.init_resource::<TypingState>();
+}
+
+pub const fn get_info() -> GameInfo {
+    GameInfo {
+        kind: GAME,
+        controls: GameControlMethod::Keyboard,
+        hint: "hint.type",
+        color: 0x33CC66FF,
+        pattern: Pattern::Solid,
+    }
+}
+
+/// All data representing the current state of this game
+#[derive(Debug, Default, Clone, Resource)]
+pub struct TypingState {
+    pub start_time: Duration,
+    pub word: Vec<char>,
+    /// Letters of the word typed so far
+    pub typed: usize,
+    pub mistakes: u32,
+}
+
+impl TypingState {
+    /// Called when starting this game to make sure the data is reset
+    pub fn reset(&mut self, start_time: Duration, word: &str) {
+        self.start_time = start_time;
+        self.word = word.chars().collect();
+        self.typed = 0;
+        self.mistakes = 0;
+    }
+
+    pub fn done(&self) -> bool {
+        self.typed >= self.word.len()
+    }
+}
+
+/// Used to track all assets for this game
+#[derive(Resource, Asset, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct TypingAssets {
+    #[dependency]
+    background: Handle<Image>,
+    #[dependency]
+    key_sound: Handle<AudioSource>,
+    #[dependency]
+    wrong_sound: Handle<AudioSource>,
+    #[dependency]
+    done_sound: Handle<AudioSource>,
+    #[dependency]
+    bgm: Handle<AudioSource>,
+}
+
+impl FromManifest for TypingAssets {
+    const PATH: &'static str = "games/typing/typing.manifest";
+
+    /// Pick all assets we want for this game out of the manifest
+    fn from_manifest(manifest: &AssetManifest) -> Result<Self, ManifestError> {
+        Ok(Self {
+            background: manifest.image("background")?,
+            key_sound: manifest.sound("key")?,
+            wrong_sound: manifest.sound("wrong")?,
+            done_sound: manifest.sound("done")?,
+            bgm: manifest.sound("bgm")?,
+        })
+    }
+}
+
+/// The typed part of the word, the rest is a [`RemainingText`] span under it
+#[derive(Debug, Component)]
+struct TypedText;
+
+#[derive(Debug, Component)]
+struct RemainingText;
+
+/// Pick a random word for `level` from the current language,
+/// or [`balance::FALLBACK_WORD`] if the language has no words for it
+fn pick_word(locale: &Locale, level: usize) -> String {
+    let tier = level.min(balance::WORD_TIERS - 1);
+    let words = locale
+        .try_get(&format!("typing.words.{tier}"))
+        .unwrap_or_default();
+    words
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .choose(&mut rand::rng())
+        .map(|word| word.to_lowercase())
+        .unwrap_or_else(|| balance::FALLBACK_WORD.to_string())
+}
+
+/// A system to spawn the typing level
+pub fn spawn(
+    mut commands: Commands,
+    gamedata: Res<GameData>,
+    assets: Res<TypingAssets>,
+    menu_assets: Res<MenuAssets>,
+    locale: Locale,
+    mut state: ResMut<TypingState>,
+    time: Res<Time>,
+) {
+    let word = pick_word(&locale, gamedata.level);
+    info!("typing {}", word);
+    state.reset(time.elapsed(), &word);
+
+    commands.spawn((
+        DespawnOnExit(GAME),             // When exiting this game despawn this entity
+        DespawnOnExit(Screen::Gameplay), // When exiting the top level game despawn this entity
+        Camera2d,
+        CameraShakeConfig::default(),
+        Camera {
+            order: -1,
+            clear_color: ClearColorConfig::None,
+            ..default()
+        },
+        Projection::from(OrthographicProjection {
+            scaling_mode: ScalingMode::FixedVertical {
+                viewport_height: balance::VIEW_HEIGHT,
+            },
+            ..OrthographicProjection::default_2d()
+        }),
+        camera::RENDERLAYER_GAME,
+    ));
+
+    commands.spawn((
+        Name::new("background"),
+        Transform::default(),
+        Visibility::default(),
+        Sprite::from_image(assets.background.clone()),
+        DespawnOnExit(GAME),
+        DespawnOnExit(Screen::Gameplay),
+        Propagate(camera::RENDERLAYER_GAME),
+    ));
+
+    let font = TextFont::from(menu_assets.font.clone()).with_font_size(100.0);
+    commands
+        .spawn((
+            widget::ui_root("typing_ui"),
+            DespawnOnExit(GAME), // When exiting this game despawn this entity
+            DespawnOnExit(Screen::Gameplay), // When exiting the top level game despawn this entity
+            Timeout::new(balance::GAME_DURATION),
+            AudioPlayer(assets.bgm.clone()),
+            children![
+                (
+                    Name::new("Word"),
+                    Text::default(),
+                    TypedText,
+                    font.clone(),
+                    TextColor(TYPED_TEXT),
+                    BaseTextColor(TYPED_TEXT),
+                    TextShadow::default(),
+                    children![(
+                        TextSpan(word),
+                        RemainingText,
+                        font,
+                        TextColor(HEADER_TEXT),
+                        BaseTextColor(HEADER_TEXT),
+                    )],
+                ),
+                // Sweeps like a stopwatch while the word is typed
+                TimeoutBar::from_foreground_color(color_u32(get_info().color))
+                    .with_pattern(get_info().pattern)
+                    .with_fill(Fill::ClockWipe {
+                        start_angle: 0.0,
+                        clockwise: true,
+                    })
+                    .with_thresholds(WARNING_THRESHOLDS)
+            ],
+        ))
+        .observe(timed_out);
+}
+
+fn timed_out(_event: On<TimedOut>, mut tx: MessageWriter<NextGame>, state: Res<TypingState>) {
+    if state.word.is_empty() {
+        // Nothing to type, so nothing to fail
+        tx.write(NextGame::from_result(GameResult::Passsed));
+        info!("timeout without a word - next game");
+    } else if !state.done() {
+        tx.write(NextGame::from_result(GameResult::Failed));
+        info!("timeout after {} mistakes - next game", state.mistakes);
+    }
+}
+
+/// Match typed characters against the next letter of the word
+fn update(
+    mut commands: Commands,
+    mut keys: MessageReader<KeyboardInput>,
+    mut state: ResMut<TypingState>,
+    assets: Res<TypingAssets>,
+    mut tx: MessageWriter<NextGame>,
+) {
+    for key in keys.read() {
+        if key.state != ButtonState::Pressed || state.done() {
+            continue;
+        }
+        let Key::Character(typed) = &key.logical_key else {
+            continue;
+        };
+
+        for typed in typed.chars().flat_map(char::to_lowercase) {
+            if state.done() {
+                break;
+            }
+            if state.word[state.typed] == typed {
+                state.typed += 1;
+                commands.spawn(sound_effect(assets.key_sound.clone()));
+            } else {
+                state.mistakes += 1;
+                commands.spawn(sound_effect(assets.wrong_sound.clone()));
+                commands.trigger(AddTrauma::new(balance::WRONG_KEY_TRAUMA));
+            }
+        }
+
+        if state.done() {
+            commands.spawn(sound_effect(assets.done_sound.clone()));
+            tx.write(NextGame::from_result(GameResult::Passsed));
+            info!("word typed - next game");
+        }
+    }
+}
+
+fn update_word(
+    state: Res<TypingState>,
+    mut typed_query: Query<&mut Text, With<TypedText>>,
+    mut remaining_query: Query<&mut TextSpan, With<RemainingText>>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+    let typed = state.word[..state.typed].iter().collect::<String>();
+    let remaining = state.word[state.typed..].iter().collect::<String>();
+    for mut text in typed_query.iter_mut() {
+        text.0.clone_from(&typed);
+    }
+    for mut span in remaining_query.iter_mut() {
+        span.0.clone_from(&remaining);
+    }
+}
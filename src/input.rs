@@ -0,0 +1,46 @@
+//! Tracks which input device the player used most recently, so feedback such as
+//! gamepad rumble and menu focus can follow the device in their hands.
+
+use bevy::{
+    input::{InputSystems, mouse::AccumulatedMouseMotion},
+    prelude::*,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<ActiveInput>();
+    app.add_systems(PreUpdate, update_active_input.after(InputSystems));
+}
+
+/// Stick movement needed before a gamepad counts as the active input
+const STICK_THRESHOLD: f32 = 0.5;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Resource)]
+pub enum ActiveInput {
+    #[default]
+    KeyboardMouse,
+    Gamepad(Entity),
+}
+
+fn update_active_input(
+    mut active: ResMut<ActiveInput>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    mouse_motion: Res<AccumulatedMouseMotion>,
+    gamepads: Query<(Entity, &Gamepad)>,
+) {
+    if keys.get_just_pressed().next().is_some()
+        || mouse.get_just_pressed().next().is_some()
+        || mouse_motion.delta != Vec2::ZERO
+    {
+        active.set_if_neq(ActiveInput::KeyboardMouse);
+    }
+
+    for (entity, gamepad) in gamepads.iter() {
+        if gamepad.get_just_pressed().next().is_some()
+            || gamepad.left_stick().length() > STICK_THRESHOLD
+            || gamepad.right_stick().length() > STICK_THRESHOLD
+        {
+            active.set_if_neq(ActiveInput::Gamepad(entity));
+        }
+    }
+}
@@ -45,6 +45,18 @@ pub fn top_left() -> Node {
     }
 }
 
+#[allow(dead_code)]
+pub fn top_right() -> Node {
+    Node {
+        right: px(10),
+        top: px(10),
+        padding: UiRect::all(Val::Px(30.0)),
+        display: Display::Block,
+        position_type: PositionType::Absolute,
+        ..default()
+    }
+}
+
 #[allow(dead_code)]
 pub fn bottom_right() -> Node {
     Node {
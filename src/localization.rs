@@ -0,0 +1,255 @@
+//! Translated text for everything the player reads.
+//!
+//! Each language has a string table in `assets/locale/<code>.strings`, one entry per line,
+//! lines starting with `#` are comments:
+//!
+//! ```text
+//! # key              text
+//! menu.play        = Play
+//! post_game.rounds = Survived {rounds} rounds
+//! ```
+//!
+//! `{name}` is replaced with the argument of that name and `\n` starts a new line.
+//! Keys missing from the current language fall back to [`FALLBACK_LANGUAGE`].
+//!
+//! UI text is usually spawned through [`crate::theme::widget`], which takes a [`Localized`]
+//! and keeps the text up to date when the language changes.
+
+use std::{borrow::Cow, error::Error, fmt};
+
+use bevy::{
+    asset::{AssetLoader, AsyncReadExt, LoadContext, io::Reader},
+    ecs::system::SystemParam,
+    platform::collections::HashMap,
+    prelude::*,
+};
+
+use crate::{AppSystems, asset_tracking::LoadResource};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_asset::<StringTable>();
+    app.init_asset_loader::<StringTableLoader>();
+    app.init_resource::<CurrentLanguage>();
+    app.load_resource::<LocaleAssets>();
+
+    app.add_systems(Update, update_localized_text.in_set(AppSystems::Update));
+}
+
+pub struct Language {
+    /// Name of the string table file
+    pub code: &'static str,
+    /// Name of the language in that language, as shown in the settings
+    pub name: &'static str,
+}
+
+/// Every language with a string table, [`CurrentLanguage`] indexes into this.
+pub const LANGUAGES: &[Language] = &[
+    Language {
+        code: "en",
+        name: "English",
+    },
+    Language {
+        code: "es",
+        name: "Español",
+    },
+];
+
+/// Index into [`LANGUAGES`] of the language whose strings are used when a key is missing
+pub const FALLBACK_LANGUAGE: usize = 0;
+
+/// Index into [`LANGUAGES`] of the language the player picked
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub struct CurrentLanguage(pub usize);
+
+impl Default for CurrentLanguage {
+    fn default() -> Self {
+        Self(FALLBACK_LANGUAGE)
+    }
+}
+
+#[derive(Resource, Asset, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct LocaleAssets {
+    /// One table per entry in [`LANGUAGES`]
+    #[dependency]
+    tables: Vec<Handle<StringTable>>,
+}
+
+impl FromWorld for LocaleAssets {
+    fn from_world(world: &mut World) -> Self {
+        let assets = world.resource::<AssetServer>();
+        Self {
+            tables: LANGUAGES
+                .iter()
+                .map(|language| assets.load(format!("locale/{}.strings", language.code)))
+                .collect(),
+        }
+    }
+}
+
+/// The translated strings of one language, keyed by id.
+#[derive(Asset, TypePath, Debug, Default)]
+pub struct StringTable(HashMap<String, String>);
+
+/// Text looked up by key in the current language.
+/// Changing the key or arguments updates the [`Text`] on the same entity.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub enum Localized {
+    Key {
+        key: Cow<'static, str>,
+        args: Vec<(&'static str, String)>,
+    },
+    /// Text that isn't translated, like numbers. Systems may also write the [`Text`] directly.
+    Verbatim(String),
+}
+
+impl Localized {
+    pub fn new(key: impl Into<Cow<'static, str>>) -> Self {
+        Self::Key {
+            key: key.into(),
+            args: Vec::new(),
+        }
+    }
+
+    pub fn verbatim(text: impl Into<String>) -> Self {
+        Self::Verbatim(text.into())
+    }
+
+    /// Replace `{name}` in the translated text with `value`.
+    pub fn with_arg(mut self, name: &'static str, value: impl fmt::Display) -> Self {
+        if let Self::Key { args, .. } = &mut self {
+            args.push((name, value.to_string()));
+        }
+        self
+    }
+}
+
+impl From<&'static str> for Localized {
+    fn from(key: &'static str) -> Self {
+        Self::new(key)
+    }
+}
+
+/// Looks up translated strings, for text that isn't shown through [`Localized`].
+#[derive(SystemParam)]
+pub struct Locale<'w> {
+    language: Res<'w, CurrentLanguage>,
+    assets: Option<Res<'w, LocaleAssets>>,
+    tables: Res<'w, Assets<StringTable>>,
+}
+
+impl Locale<'_> {
+    /// The text for `key` with `{name}` arguments filled in.
+    /// Shows the key itself if no language has it, so missing strings stand out.
+    pub fn get(&self, key: &str, args: &[(&str, String)]) -> String {
+        let Some(mut text) = self.try_get(key) else {
+            return key.to_string();
+        };
+
+        for (name, value) in args {
+            text = text.replace(&format!("{{{name}}}"), value);
+        }
+        text
+    }
+
+    /// The text for `key`, or `None` if no language has it.
+    pub fn try_get(&self, key: &str) -> Option<String> {
+        self.lookup(self.language.0, key)
+            .or_else(|| self.lookup(FALLBACK_LANGUAGE, key))
+    }
+
+    pub fn text(&self, localized: &Localized) -> String {
+        match localized {
+            Localized::Key { key, args } => self.get(key, args),
+            Localized::Verbatim(text) => text.clone(),
+        }
+    }
+
+    /// Whether translated text has to be looked up again.
+    pub fn is_changed(&self) -> bool {
+        self.language.is_changed() || self.assets.as_ref().is_some_and(|assets| assets.is_added())
+    }
+
+    fn lookup(&self, language: usize, key: &str) -> Option<String> {
+        let handle = self.assets.as_ref()?.tables.get(language)?;
+        self.tables.get(handle)?.0.get(key).cloned()
+    }
+}
+
+fn update_localized_text(
+    locale: Locale,
+    mut table_events: MessageReader<AssetEvent<StringTable>>,
+    mut texts: Query<(Ref<Localized>, &mut Text)>,
+) {
+    // Tables change when hot reloaded
+    let refresh = locale.is_changed() || table_events.read().count() > 0;
+
+    for (localized, mut text) in &mut texts {
+        let translated = matches!(*localized, Localized::Key { .. });
+        if localized.is_changed() || (refresh && translated) {
+            text.0 = locale.text(&localized);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum StringTableError {
+    Io(std::io::Error),
+    Parse { line: usize },
+}
+
+impl fmt::Display for StringTableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "could not read string table: {error}"),
+            Self::Parse { line } => write!(f, "line {line}: expected `<key> = <text>`"),
+        }
+    }
+}
+
+impl Error for StringTableError {}
+
+impl From<std::io::Error> for StringTableError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+#[derive(Default, TypePath)]
+pub struct StringTableLoader;
+
+impl AssetLoader for StringTableLoader {
+    type Asset = StringTable;
+    type Settings = ();
+    type Error = StringTableError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<StringTable, StringTableError> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text).await?;
+
+        let mut table = HashMap::new();
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or(StringTableError::Parse { line: index + 1 })?;
+            table.insert(key.trim().to_string(), value.trim().replace("\\n", "\n"));
+        }
+
+        Ok(StringTable(table))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["strings"]
+    }
+}
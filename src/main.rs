@@ -3,8 +3,10 @@
 // Disable console on Windows for non-dev builds.
 #![cfg_attr(not(feature = "dev"), windows_subsystem = "windows")]
 
+mod accessibility;
 mod animation;
 mod app;
+mod asset_manifest;
 mod asset_tracking;
 mod audio;
 mod backgrounds;
@@ -15,8 +17,10 @@ mod dev_tools;
 mod easing;
 mod float;
 mod games;
+mod input;
 mod layout;
 mod lifetime;
+mod localization;
 mod menus;
 mod movement;
 mod random;
@@ -24,6 +28,7 @@ mod screens;
 mod theme;
 mod timeout;
 mod transition;
+mod tween;
 mod visibility;
 
 use bevy::{
@@ -84,7 +89,6 @@ impl Plugin for AppPlugin {
             theme::plugin,
             games::plugin,
             movement::plugin,
-            random::plugin,
             lifetime::plugin,
             float::plugin,
             timeout::plugin,
@@ -93,7 +97,14 @@ impl Plugin for AppPlugin {
             controls::plugin,
         ));
 
-        app.add_plugins((backgrounds::plugin, visibility::plugin));
+        app.add_plugins((
+            backgrounds::plugin,
+            visibility::plugin,
+            tween::plugin,
+            input::plugin,
+            localization::plugin,
+            accessibility::plugin,
+        ));
 
         // Order new `AppSystems` variants by adding them here:
         app.configure_sets(
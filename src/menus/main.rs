@@ -5,6 +5,7 @@ use bevy::prelude::*;
 use crate::{
     app,
     audio::music,
+    localization::Localized,
     menus::{Menu, MenuAssets},
     screens::{self},
     theme::widget,
@@ -22,32 +23,37 @@ fn spawn_main_menu(mut commands: Commands, assets: Res<MenuAssets>) {
         music(assets.bgm.clone()),
         #[cfg(not(target_family = "wasm"))]
         children![
-            widget::header(app::NAME, assets.font.clone()),
+            widget::header(Localized::verbatim(app::NAME), assets.font.clone()),
             widget::image_button(
-                "Play",
+                "menu.play",
                 screens::enter_loading_or_gameplay_screen,
                 assets.button.clone(),
                 assets.font.clone()
             ),
             widget::image_button(
-                "Settings",
+                "menu.settings",
                 open_settings_menu,
                 assets.button.clone(),
                 assets.font.clone()
             ),
-            widget::image_button("Exit", exit_app, assets.button.clone(), assets.font.clone()),
+            widget::image_button(
+                "menu.exit",
+                exit_app,
+                assets.button.clone(),
+                assets.font.clone()
+            ),
         ],
         #[cfg(target_family = "wasm")]
         children![
-            widget::header(app::NAME, assets.font.clone()),
+            widget::header(Localized::verbatim(app::NAME), assets.font.clone()),
             widget::image_button(
-                "Play",
+                "menu.play",
                 screens::enter_loading_or_gameplay_screen,
                 assets.button.clone(),
                 assets.font.clone()
             ),
             widget::image_button(
-                "Settings",
+                "menu.settings",
                 open_settings_menu,
                 assets.button.clone(),
                 assets.font.clone()
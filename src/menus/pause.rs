@@ -22,21 +22,21 @@ fn spawn_pause_menu(mut commands: Commands, assets: Res<MenuAssets>) {
         GlobalZIndex(2),
         DespawnOnExit(Menu::Pause),
         children![
-            widget::header("Game paused", assets.font.clone()),
+            widget::header("pause.title", assets.font.clone()),
             widget::image_button(
-                "Continue",
+                "pause.continue",
                 close_menu,
                 assets.button.clone(),
                 assets.font.clone()
             ),
             widget::image_button(
-                "Settings",
+                "menu.settings",
                 open_settings_menu,
                 assets.button.clone(),
                 assets.font.clone()
             ),
             widget::image_button(
-                "Quit to title",
+                "pause.quit_to_title",
                 quit_to_title,
                 assets.button.clone(),
                 assets.font.clone()
@@ -5,9 +5,15 @@
 use bevy::{audio::Volume, input::common_conditions::input_just_pressed, prelude::*};
 
 use crate::{
+    accessibility::ReducedMotion,
+    games::camera::shake::ShakeSettings,
+    localization::{CurrentLanguage, LANGUAGES, Localized},
     menus::{Menu, MenuAssets},
     screens::Screen,
-    theme::prelude::*,
+    theme::{
+        palette::{COLOR_MODES, ColorMode},
+        prelude::*,
+    },
 };
 
 pub(super) fn plugin(app: &mut App) {
@@ -16,11 +22,6 @@ pub(super) fn plugin(app: &mut App) {
         Update,
         go_back.run_if(in_state(Menu::Settings).and(input_just_pressed(KeyCode::Escape))),
     );
-
-    app.add_systems(
-        Update,
-        update_global_volume_label.run_if(in_state(Menu::Settings)),
-    );
 }
 
 fn spawn_settings_menu(mut commands: Commands, assets: Res<MenuAssets>) {
@@ -29,10 +30,10 @@ fn spawn_settings_menu(mut commands: Commands, assets: Res<MenuAssets>) {
         GlobalZIndex(2),
         DespawnOnExit(Menu::Settings),
         children![
-            widget::header("Settings", assets.font.clone()),
+            widget::header("settings.title", assets.font.clone()),
             settings_grid(assets.font.clone()),
             widget::image_button(
-                "Back",
+                "menu.back",
                 go_back_on_click,
                 assets.button.clone(),
                 assets.font.clone()
@@ -52,64 +53,86 @@ fn settings_grid(font: Handle<Font>) -> impl Bundle {
             ..default()
         },
         children![
-            (
-                widget::label("Master Volume", font.clone()),
-                Node {
-                    justify_self: JustifySelf::End,
-                    ..default()
-                }
+            setting_label("settings.master_volume", font.clone()),
+            widget::slider(
+                Binding::resource::<GlobalVolume>(
+                    |global_volume| global_volume.volume.to_linear(),
+                    |global_volume, linear| global_volume.volume = Volume::Linear(linear),
+                ),
+                MIN_VOLUME,
+                MAX_VOLUME,
+                0.1,
+                format_percent,
+                font.clone(),
+            ),
+            setting_label("settings.screen_shake", font.clone()),
+            widget::slider(
+                Binding::resource::<ShakeSettings>(
+                    |shake| shake.intensity,
+                    |shake, intensity| shake.intensity = intensity,
+                ),
+                MIN_SHAKE,
+                MAX_SHAKE,
+                0.1,
+                format_percent,
+                font.clone(),
+            ),
+            setting_label("settings.reduced_motion", font.clone()),
+            widget::toggle(
+                Binding::resource::<ReducedMotion>(
+                    |reduced_motion| reduced_motion.0,
+                    |reduced_motion, enabled| reduced_motion.0 = enabled,
+                ),
+                font.clone(),
+            ),
+            setting_label("settings.color_mode", font.clone()),
+            widget::selector(
+                Binding::resource::<ColorMode>(
+                    |color_mode| {
+                        COLOR_MODES
+                            .iter()
+                            .position(|mode| mode == color_mode)
+                            .unwrap_or_default()
+                    },
+                    |color_mode, index| *color_mode = COLOR_MODES[index],
+                ),
+                COLOR_MODES.map(ColorMode::name_key),
+                font.clone(),
+            ),
+            setting_label("settings.language", font.clone()),
+            widget::selector(
+                Binding::resource::<CurrentLanguage>(
+                    |language| language.0,
+                    |language, index| language.0 = index,
+                ),
+                LANGUAGES
+                    .iter()
+                    .map(|language| Localized::verbatim(language.name)),
+                font,
             ),
-            global_volume_widget(font),
         ],
     )
 }
 
-fn global_volume_widget(font: Handle<Font>) -> impl Bundle {
+fn setting_label(key: &'static str, font: Handle<Font>) -> impl Bundle {
     (
-        Name::new("Global Volume Widget"),
+        widget::label(key, font),
         Node {
-            justify_self: JustifySelf::Start,
+            justify_self: JustifySelf::End,
             ..default()
         },
-        children![
-            widget::button_small("-", lower_global_volume, font.clone()),
-            (
-                Name::new("Current Volume"),
-                Node {
-                    padding: UiRect::horizontal(px(10)),
-                    justify_content: JustifyContent::Center,
-                    ..default()
-                },
-                children![(widget::label("", font.clone()), GlobalVolumeLabel)],
-            ),
-            widget::button_small("+", raise_global_volume, font.clone()),
-        ],
     )
 }
 
 const MIN_VOLUME: f32 = 0.0;
 const MAX_VOLUME: f32 = 3.0;
 
-fn lower_global_volume(_: On<Pointer<Click>>, mut global_volume: ResMut<GlobalVolume>) {
-    let linear = (global_volume.volume.to_linear() - 0.1).max(MIN_VOLUME);
-    global_volume.volume = Volume::Linear(linear);
-}
-
-fn raise_global_volume(_: On<Pointer<Click>>, mut global_volume: ResMut<GlobalVolume>) {
-    let linear = (global_volume.volume.to_linear() + 0.1).min(MAX_VOLUME);
-    global_volume.volume = Volume::Linear(linear);
-}
-
-#[derive(Component, Reflect)]
-#[reflect(Component)]
-struct GlobalVolumeLabel;
+const MIN_SHAKE: f32 = 0.0;
+const MAX_SHAKE: f32 = 1.0;
 
-fn update_global_volume_label(
-    global_volume: Res<GlobalVolume>,
-    mut label: Single<&mut Text, With<GlobalVolumeLabel>>,
-) {
-    let percent = 100.0 * global_volume.volume.to_linear();
-    label.0 = format!("{percent:3.0}%");
+fn format_percent(value: f32) -> String {
+    let percent = 100.0 * value;
+    format!("{percent:3.0}%")
 }
 
 fn go_back_on_click(
@@ -44,6 +44,9 @@ pub struct TopDownMovementController {
     /// Maximum speed in world units per second.
     /// 1 world unit = 1 pixel when using the default 2D camera and no physics engine.
     pub max_speed: Vec2,
+
+    /// Scales [`Self::max_speed`], for effects that slow the character down for a while.
+    pub speed_multiplier: f32,
 }
 
 impl Default for TopDownMovementController {
@@ -52,6 +55,7 @@ impl Default for TopDownMovementController {
             intent: Vec2::ZERO,
             // 400 pixels per second is a nice default, but we can still vary this per character.
             max_speed: Vec2 { x: 400.0, y: 400.0 },
+            speed_multiplier: 1.0,
         }
     }
 }
@@ -61,7 +65,7 @@ fn apply_movement(
     mut movement_query: Query<(&TopDownMovementController, &mut Transform)>,
 ) {
     for (controller, mut transform) in &mut movement_query {
-        let velocity = controller.max_speed * controller.intent;
+        let velocity = controller.max_speed * controller.speed_multiplier * controller.intent;
         transform.translation += velocity.extend(0.0) * time.delta_secs();
     }
 }
@@ -4,26 +4,92 @@
 use bevy::prelude::*;
 
 use crate::{
-    asset_tracking::ResourceHandles, menus::MenuAssets, screens::Screen, theme::prelude::*,
+    AppSystems, asset_tracking::ResourceHandles, controls::progress_bar::ProgressBar,
+    localization::Localized, menus::MenuAssets, screens::Screen, theme::prelude::*,
 };
 
+const BAR_COLOR: Color = Color::srgb_u8(18, 164, 215);
+const BAR_BACKGROUND: Color = Color::srgb(0.15, 0.15, 0.4);
+
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(OnEnter(Screen::Loading), spawn_loading_screen);
 
     app.add_systems(
         Update,
-        enter_gameplay_screen.run_if(in_state(Screen::Loading).and(all_assets_loaded)),
+        (
+            update_loading_progress,
+            enter_gameplay_screen.run_if(all_assets_loaded),
+        )
+            .in_set(AppSystems::Update)
+            .run_if(in_state(Screen::Loading)),
     );
 }
 
+#[derive(Component)]
+struct LoadingBar;
+
+#[derive(Component)]
+struct LoadingStatus;
+
+#[derive(Component)]
+struct LoadingError;
+
 fn spawn_loading_screen(mut commands: Commands, assets: Res<MenuAssets>) {
     commands.spawn((
         widget::ui_root("Loading Screen"),
         DespawnOnExit(Screen::Loading),
-        children![widget::label("Loading...", assets.font.clone())],
+        children![
+            widget::label("loading.title", assets.font.clone()),
+            (
+                Name::new("Loading Bar"),
+                Node {
+                    width: px(400),
+                    height: px(30),
+                    padding: UiRect::all(px(5)),
+                    ..default()
+                },
+                BackgroundColor(BAR_BACKGROUND),
+                ProgressBar {
+                    color: BAR_COLOR,
+                    ..default()
+                },
+                LoadingBar,
+            ),
+            (
+                widget::label("loading.status", assets.font.clone()),
+                LoadingStatus
+            ),
+            (
+                widget::label(Localized::verbatim(""), assets.font.clone()),
+                ui_palette::BaseTextColor(ui_palette::ERROR_TEXT),
+                LoadingError,
+            ),
+        ],
     ));
 }
 
+fn update_loading_progress(
+    resource_handles: Res<ResourceHandles>,
+    mut bar: Single<&mut ProgressBar, With<LoadingBar>>,
+    mut status: Single<&mut Localized, (With<LoadingStatus>, Without<LoadingError>)>,
+    mut error: Single<&mut Localized, (With<LoadingError>, Without<LoadingStatus>)>,
+) {
+    bar.progress = resource_handles.progress();
+
+    let waiting = resource_handles.waiting_names().collect::<Vec<_>>();
+    status.set_if_neq(
+        Localized::new("loading.status")
+            .with_arg("loaded", resource_handles.loaded_count())
+            .with_arg("total", resource_handles.total_count())
+            .with_arg("waiting", waiting.join(", ")),
+    );
+
+    let failed = resource_handles.failed_paths();
+    if !failed.is_empty() {
+        error.set_if_neq(Localized::new("loading.failed").with_arg("paths", failed.join("\n")));
+    }
+}
+
 fn enter_gameplay_screen(mut next_screen: ResMut<NextState<Screen>>) {
     next_screen.set(Screen::Gameplay);
 }
@@ -4,6 +4,7 @@ use duration_string::DurationString;
 use crate::{
     backgrounds::BackgroundAssets,
     games::GameData,
+    localization::Localized,
     menus::MenuAssets,
     screens::{self, Screen},
     theme::widget,
@@ -26,21 +27,24 @@ pub fn spawn(
         ImageNode::new(assets.index(data.random)),
         DespawnOnExit(SCREEN),
         children![
-            widget::header("Game Over", menu_assets.font.clone()),
+            widget::header("post_game.title", menu_assets.font.clone()),
             widget::label_with_shadow(
-                format!("Played for {}", DurationString::from(data.elapsed)),
+                Localized::new("post_game.played_for")
+                    .with_arg("time", DurationString::from(data.elapsed)),
                 menu_assets.font.clone()
             ),
             widget::label_with_shadow(
-                format!("Survived {} rounds", data.round),
+                Localized::new("post_game.rounds").with_arg("rounds", data.round),
                 menu_assets.font.clone()
             ),
             widget::label_with_shadow(
-                format!("Passed {} and failed {} games", data.passed, data.failed),
+                Localized::new("post_game.results")
+                    .with_arg("passed", data.passed)
+                    .with_arg("failed", data.failed),
                 menu_assets.font.clone()
             ),
             widget::image_button(
-                "Again?",
+                "post_game.again",
                 screens::enter_loading_or_gameplay_screen,
                 menu_assets.button.clone(),
                 menu_assets.font.clone()
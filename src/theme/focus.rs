@@ -0,0 +1,285 @@
+//! Keyboard and gamepad navigation between buttons.
+//!
+//! Tab / Shift+Tab walk the buttons in tab order, the arrow keys and d-pad move to the nearest
+//! button in that direction, and Enter or the gamepad South button activate the focused button
+//! by sending it a [`Pointer<Click>`] so it behaves exactly like a mouse click.
+//! Focused widgets with [`AdjustWithArrows`] get an [`Adjust`] for left and right instead.
+
+use std::time::Duration;
+
+use bevy::{
+    asset::uuid::Uuid,
+    camera::NormalizedRenderTarget,
+    picking::{
+        backend::HitData,
+        pointer::{Location, PointerButton, PointerId},
+    },
+    prelude::*,
+};
+
+use crate::{
+    AppSystems,
+    audio::sound_effect,
+    input::ActiveInput,
+    theme::{
+        interaction::{InteractionAssets, InteractionPalette},
+        palette::ColorMode,
+    },
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<Focus>();
+    app.add_systems(
+        Update,
+        (clear_missing_focus, navigate, activate)
+            .chain()
+            .in_set(AppSystems::RecordInput),
+    );
+}
+
+/// Pointer used for clicks sent by keyboard or gamepad activation
+const FOCUS_POINTER: PointerId =
+    PointerId::Custom(Uuid::from_u128(0x5d4c_1f0e_7b3a_4e2f_9c61_0a8d_f3b2_c7e4));
+
+/// The button that keyboard and gamepad input acts on
+#[derive(Resource, Debug, Default)]
+pub struct Focus(pub Option<Entity>);
+
+/// Overrides where a button is in the tab order. Lower comes first, buttons without it
+/// count as 0 and are otherwise ordered top to bottom, left to right.
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct TabOrder(pub i32);
+
+/// Left and right change the value of this widget while focused, instead of moving focus
+#[derive(Component, Debug, Default, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct AdjustWithArrows;
+
+/// Step the value of a focused [`AdjustWithArrows`] widget up or down
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct Adjust {
+    pub entity: Entity,
+    /// -1 for left, 1 for right
+    pub step: i32,
+}
+
+type Focusable<'a> = (
+    Entity,
+    &'a UiGlobalTransform,
+    &'a InheritedVisibility,
+    Option<&'a TabOrder>,
+);
+
+fn clear_missing_focus(mut focus: ResMut<Focus>, buttons: Query<(), With<Button>>) {
+    if let Some(entity) = focus.0
+        && !buttons.contains(entity)
+    {
+        focus.0 = None;
+    }
+}
+
+fn navigate(
+    mut commands: Commands,
+    mut focus: ResMut<Focus>,
+    keys: Res<ButtonInput<KeyCode>>,
+    active_input: Res<ActiveInput>,
+    gamepads: Query<&Gamepad>,
+    buttons: Query<Focusable, (With<Button>, With<InteractionPalette>)>,
+    adjustable: Query<(), With<AdjustWithArrows>>,
+    mut palettes: Query<(
+        &InteractionPalette,
+        Option<&mut BackgroundColor>,
+        Option<&mut ImageNode>,
+    )>,
+    interaction_assets: Option<Res<InteractionAssets>>,
+    color_mode: Res<ColorMode>,
+) {
+    let gamepad_pressed = |button| gamepad_just_pressed(&active_input, &gamepads, button);
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    let mut visible = buttons
+        .iter()
+        .filter(|(_, _, visibility, _)| visibility.get())
+        .map(|(entity, transform, _, order)| {
+            (
+                entity,
+                transform.translation,
+                order.map_or(0, |order| order.0),
+            )
+        })
+        .collect::<Vec<_>>();
+    if visible.is_empty() {
+        return;
+    }
+    visible.sort_by(|a, b| {
+        a.2.cmp(&b.2)
+            .then(a.1.y.total_cmp(&b.1.y))
+            .then(a.1.x.total_cmp(&b.1.x))
+    });
+
+    let direction = if keys.just_pressed(KeyCode::ArrowUp) || gamepad_pressed(GamepadButton::DPadUp)
+    {
+        Some(Vec2::NEG_Y)
+    } else if keys.just_pressed(KeyCode::ArrowDown) || gamepad_pressed(GamepadButton::DPadDown) {
+        Some(Vec2::Y)
+    } else if keys.just_pressed(KeyCode::ArrowLeft) || gamepad_pressed(GamepadButton::DPadLeft) {
+        Some(Vec2::NEG_X)
+    } else if keys.just_pressed(KeyCode::ArrowRight) || gamepad_pressed(GamepadButton::DPadRight) {
+        Some(Vec2::X)
+    } else {
+        None
+    };
+    let tab = keys.just_pressed(KeyCode::Tab);
+    if direction.is_none() && !tab {
+        return;
+    }
+
+    if let Some(entity) = focus.0
+        && adjustable.contains(entity)
+        && let Some(direction) = direction
+        && direction.y == 0.0
+    {
+        commands.trigger(Adjust {
+            entity,
+            step: direction.x as i32,
+        });
+        return;
+    }
+
+    let current = focus
+        .0
+        .and_then(|entity| visible.iter().position(|(other, _, _)| *other == entity));
+
+    let next = match (current, direction) {
+        // The first press only shows where the focus is
+        (None, _) => visible[0].0,
+        (Some(index), None) => {
+            let len = visible.len();
+            let index = if shift {
+                (index + len - 1) % len
+            } else {
+                (index + 1) % len
+            };
+            visible[index].0
+        }
+        (Some(index), Some(direction)) => {
+            let from = visible[index].1;
+            visible
+                .iter()
+                .filter_map(|(entity, position, _)| {
+                    let offset = *position - from;
+                    let along = offset.dot(direction);
+                    // Prefer buttons in line with the current one over closer diagonal ones
+                    let across = offset.perp_dot(direction).abs();
+                    (along > 0.0).then_some((*entity, along + across * 2.0))
+                })
+                .min_by(|a, b| a.1.total_cmp(&b.1))
+                .map_or(visible[index].0, |(entity, _)| entity)
+        }
+    };
+
+    if focus.0 == Some(next) {
+        return;
+    }
+
+    if let Some(previous) = focus.0 {
+        set_palette_color(&mut palettes, previous, |palette| {
+            color_mode.adjust(palette.none)
+        });
+    }
+    set_palette_color(&mut palettes, next, |palette| {
+        color_mode.adjust(palette.hovered)
+    });
+    focus.0 = Some(next);
+
+    if let Some(interaction_assets) = interaction_assets {
+        commands.spawn(sound_effect(interaction_assets.hover.clone()));
+    }
+}
+
+fn activate(
+    mut commands: Commands,
+    focus: Res<Focus>,
+    keys: Res<ButtonInput<KeyCode>>,
+    active_input: Res<ActiveInput>,
+    gamepads: Query<&Gamepad>,
+    buttons: Query<&UiGlobalTransform, With<Button>>,
+) {
+    let Some(entity) = focus.0 else {
+        return;
+    };
+    let Ok(transform) = buttons.get(entity) else {
+        return;
+    };
+
+    if !keys.any_just_pressed([KeyCode::Enter, KeyCode::NumpadEnter])
+        && !gamepad_just_pressed(&active_input, &gamepads, GamepadButton::South)
+    {
+        return;
+    }
+
+    let location = Location {
+        target: NormalizedRenderTarget::None {
+            width: 0,
+            height: 0,
+        },
+        position: transform.translation,
+    };
+    let hit = HitData::new(Entity::PLACEHOLDER, 0.0, None, None);
+    commands.trigger(Pointer::new(
+        FOCUS_POINTER,
+        location.clone(),
+        Click {
+            button: PointerButton::Primary,
+            hit: hit.clone(),
+            duration: Duration::ZERO,
+        },
+        entity,
+    ));
+    // Puts the palette back to hovered, as the button stays focused
+    commands.trigger(Pointer::new(
+        FOCUS_POINTER,
+        location,
+        Release {
+            button: PointerButton::Primary,
+            hit,
+        },
+        entity,
+    ));
+}
+
+/// Only the gamepad the player is holding navigates, see [`ActiveInput`].
+fn gamepad_just_pressed(
+    active_input: &ActiveInput,
+    gamepads: &Query<&Gamepad>,
+    button: GamepadButton,
+) -> bool {
+    match *active_input {
+        ActiveInput::Gamepad(entity) => gamepads
+            .get(entity)
+            .is_ok_and(|gamepad| gamepad.just_pressed(button)),
+        ActiveInput::KeyboardMouse => false,
+    }
+}
+
+fn set_palette_color(
+    palettes: &mut Query<(
+        &InteractionPalette,
+        Option<&mut BackgroundColor>,
+        Option<&mut ImageNode>,
+    )>,
+    entity: Entity,
+    color: impl Fn(&InteractionPalette) -> Color,
+) {
+    let Ok((palette, background, image)) = palettes.get_mut(entity) else {
+        return;
+    };
+
+    // Matches the interaction observers, image buttons tint the image instead of the background
+    if let Some(mut image) = image {
+        image.color = color(palette);
+    } else if let Some(mut background) = background {
+        *background = color(palette).into();
+    }
+}
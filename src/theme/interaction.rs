@@ -1,6 +1,6 @@
 use bevy::prelude::*;
 
-use crate::{asset_tracking::LoadResource, audio::sound_effect};
+use crate::{asset_tracking::LoadResource, audio::sound_effect, theme::palette::ColorMode};
 
 pub(super) fn plugin(app: &mut App) {
     app.add_observer(apply_interaction_palette_on_click);
@@ -19,7 +19,7 @@ pub(super) fn plugin(app: &mut App) {
 
 /// Palette for widget interactions. Add this to an entity that supports
 /// [`Interaction`]s, such as a button, to change its [`BackgroundColor`] based
-/// on the current interaction state.
+/// on the current interaction state. The colors are adjusted for the [`ColorMode`].
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component)]
 pub struct InteractionPalette {
@@ -31,96 +31,104 @@ pub struct InteractionPalette {
 fn apply_interaction_palette_on_click(
     click: On<Pointer<Click>>,
     mut palette_query: Query<(&InteractionPalette, &mut BackgroundColor), Without<ImageNode>>,
+    color_mode: Res<ColorMode>,
 ) {
     let Ok((palette, mut bg)) = palette_query.get_mut(click.event_target()) else {
         return;
     };
 
-    *bg = palette.pressed.into();
+    *bg = color_mode.adjust(palette.pressed).into();
 }
 
 fn apply_interaction_palette_on_click_image(
     click: On<Pointer<Click>>,
     mut palette_query: Query<(&InteractionPalette, &mut ImageNode)>,
+    color_mode: Res<ColorMode>,
 ) {
     let Ok((palette, mut image)) = palette_query.get_mut(click.event_target()) else {
         return;
     };
 
-    image.color = palette.pressed;
+    image.color = color_mode.adjust(palette.pressed);
 }
 
 fn apply_interaction_palette_on_release(
     click: On<Pointer<Release>>,
     mut palette_query: Query<(&InteractionPalette, &mut BackgroundColor), Without<ImageNode>>,
+    color_mode: Res<ColorMode>,
 ) {
     let Ok((palette, mut bg)) = palette_query.get_mut(click.event_target()) else {
         return;
     };
 
-    *bg = palette.hovered.into();
+    *bg = color_mode.adjust(palette.hovered).into();
 }
 
 fn apply_interaction_palette_on_release_image(
     click: On<Pointer<Release>>,
     mut palette_query: Query<(&InteractionPalette, &mut ImageNode)>,
+    color_mode: Res<ColorMode>,
 ) {
     let Ok((palette, mut image)) = palette_query.get_mut(click.event_target()) else {
         return;
     };
 
-    image.color = palette.hovered;
+    image.color = color_mode.adjust(palette.hovered);
 }
 
 fn apply_interaction_palette_on_over(
     over: On<Pointer<Over>>,
     mut palette_query: Query<(&InteractionPalette, &mut BackgroundColor), Without<ImageNode>>,
+    color_mode: Res<ColorMode>,
 ) {
     let Ok((palette, mut bg)) = palette_query.get_mut(over.event_target()) else {
         return;
     };
 
-    *bg = palette.hovered.into();
+    *bg = color_mode.adjust(palette.hovered).into();
 }
 
 fn apply_interaction_palette_on_over_image(
     over: On<Pointer<Over>>,
     mut palette_query: Query<(&InteractionPalette, &mut ImageNode)>,
+    color_mode: Res<ColorMode>,
 ) {
     let Ok((palette, mut image)) = palette_query.get_mut(over.event_target()) else {
         return;
     };
 
-    image.color = palette.hovered;
+    image.color = color_mode.adjust(palette.hovered);
 }
 
 fn apply_interaction_palette_on_out(
     out: On<Pointer<Out>>,
     mut palette_query: Query<(&InteractionPalette, &mut BackgroundColor), Without<ImageNode>>,
+    color_mode: Res<ColorMode>,
 ) {
     let Ok((palette, mut bg)) = palette_query.get_mut(out.event_target()) else {
         return;
     };
 
-    *bg = palette.none.into();
+    *bg = color_mode.adjust(palette.none).into();
 }
 
 fn apply_interaction_palette_on_out_image(
     out: On<Pointer<Out>>,
     mut palette_query: Query<(&InteractionPalette, &mut ImageNode)>,
+    color_mode: Res<ColorMode>,
 ) {
     let Ok((palette, mut image)) = palette_query.get_mut(out.event_target()) else {
         return;
     };
 
-    image.color = palette.none;
+    image.color = color_mode.adjust(palette.none);
 }
 
 #[derive(Resource, Asset, Clone, Reflect)]
 #[reflect(Resource)]
-struct InteractionAssets {
+pub(super) struct InteractionAssets {
     #[dependency]
-    hover: Handle<AudioSource>,
+    pub(super) hover: Handle<AudioSource>,
     #[dependency]
     click: Handle<AudioSource>,
 }
@@ -3,17 +3,26 @@
 // Unused utilities may trigger this lints undesirably.
 #![allow(dead_code)]
 
+pub mod focus;
 pub mod interaction;
 pub mod palette;
+pub mod value_widget;
 pub mod widget;
 
 #[allow(unused_imports)]
 pub mod prelude {
-    pub use super::{interaction::InteractionPalette, palette as ui_palette, widget};
+    pub use super::{
+        interaction::InteractionPalette, palette as ui_palette, value_widget::Binding, widget,
+    };
 }
 
 use bevy::prelude::*;
 
 pub(super) fn plugin(app: &mut App) {
-    app.add_plugins(interaction::plugin);
+    app.add_plugins((
+        interaction::plugin,
+        focus::plugin,
+        palette::plugin,
+        value_widget::plugin,
+    ));
 }
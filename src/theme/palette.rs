@@ -1,5 +1,12 @@
 use bevy::prelude::*;
 
+use crate::theme::interaction::InteractionPalette;
+
+pub(super) fn plugin(app: &mut App) {
+    app.init_resource::<ColorMode>();
+    app.add_systems(Update, (apply_text_color_mode, apply_palette_color_mode));
+}
+
 pub const LABEL_TEXT: Color = Color::srgb(0.15, 0.15, 0.4);
 pub const HEADER_TEXT: Color = Color::srgb(0.15, 0.15, 0.4);
 
@@ -11,3 +18,137 @@ pub const BUTTON_BACKGROUND: Color = Color::srgb_u8(1, 99, 138);
 pub const BUTTON_HOVERED_BACKGROUND: Color = Color::srgb_u8(18, 164, 215);
 /// #3d4999
 pub const BUTTON_PRESSED_BACKGROUND: Color = Color::srgb(0.239, 0.286, 0.600);
+/// White overlay on the button background
+pub const SLIDER_FILL: Color = Color::srgba(1.0, 1.0, 1.0, 0.35);
+/// Fill of the fever thermometer
+pub const FEVER: Color = Color::srgb(1.0, 0.0, 0.0);
+/// Text telling why loading failed
+pub const ERROR_TEXT: Color = Color::srgb(0.8, 0.1, 0.1);
+/// Letters already typed in the typing game
+pub const TYPED_TEXT: Color = Color::srgb(0.1, 0.6, 0.2);
+
+/// How colors are adjusted for the player's eyes, picked in the settings.
+/// Colors in this module and from [`crate::color::color_u32`] are the [`ColorMode::Standard`]
+/// ones, use [`ColorMode::adjust`] before showing them.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Resource)]
+pub enum ColorMode {
+    #[default]
+    Standard,
+    /// Red weak
+    Protanopia,
+    /// Green weak
+    Deuteranopia,
+    /// Blue weak
+    Tritanopia,
+    /// Very dark and very bright colors only
+    HighContrast,
+}
+
+/// Every [`ColorMode`] in the order shown in the settings
+pub const COLOR_MODES: [ColorMode; 5] = [
+    ColorMode::Standard,
+    ColorMode::Protanopia,
+    ColorMode::Deuteranopia,
+    ColorMode::Tritanopia,
+    ColorMode::HighContrast,
+];
+
+impl ColorMode {
+    /// Localization key of the name shown in the settings
+    pub fn name_key(self) -> &'static str {
+        match self {
+            Self::Standard => "color_mode.standard",
+            Self::Protanopia => "color_mode.protanopia",
+            Self::Deuteranopia => "color_mode.deuteranopia",
+            Self::Tritanopia => "color_mode.tritanopia",
+            Self::HighContrast => "color_mode.high_contrast",
+        }
+    }
+
+    pub fn adjust(self, color: Color) -> Color {
+        match self {
+            Self::Standard => color,
+            Self::Protanopia => daltonize(color, |lms| {
+                Vec3::new(2.02344 * lms.y - 2.52581 * lms.z, lms.y, lms.z)
+            }),
+            Self::Deuteranopia => daltonize(color, |lms| {
+                Vec3::new(lms.x, 0.494207 * lms.x + 1.24827 * lms.z, lms.z)
+            }),
+            Self::Tritanopia => daltonize(color, |lms| {
+                Vec3::new(lms.x, lms.y, -0.395913 * lms.x + 0.801109 * lms.y)
+            }),
+            Self::HighContrast => {
+                let mut color = Oklcha::from(color);
+                color.lightness = if color.lightness > 0.5 { 0.97 } else { 0.15 };
+                color.chroma = (color.chroma * 1.5).min(0.3);
+                color.into()
+            }
+        }
+    }
+}
+
+/// Shift the parts of `color` lost to a color vision deficiency into channels that are
+/// still seen, `simulate` maps LMS cone responses to what the deficient eye sees.
+fn daltonize(color: Color, simulate: impl Fn(Vec3) -> Vec3) -> Color {
+    // Column major, from Fidaner, Lin and Ozguven, "Analysis of Color Blindness"
+    #[rustfmt::skip]
+    const RGB_TO_LMS: Mat3 = Mat3::from_cols_array(&[
+        17.8824, 3.45565, 0.0299566,
+        43.5161, 27.1554, 0.184309,
+        4.11935, 3.86714, 1.46709,
+    ]);
+    #[rustfmt::skip]
+    const LMS_TO_RGB: Mat3 = Mat3::from_cols_array(&[
+        0.080_944_45, -0.010_248_533, -0.000_365_296_94,
+        -0.130_504_41, 0.054_019_33, -0.004_121_614_7,
+        0.116_721_07, -0.113_614_71, 0.693_511_4,
+    ]);
+
+    let srgba = Srgba::from(color);
+    let rgb = Vec3::new(srgba.red, srgba.green, srgba.blue);
+    let seen = LMS_TO_RGB * simulate(RGB_TO_LMS * rgb);
+    let error = rgb - seen;
+    let shifted = Vec3::new(0.0, 0.7 * error.x + error.y, 0.7 * error.x + error.z);
+    let rgb = (rgb + shifted).clamp(Vec3::ZERO, Vec3::ONE);
+    Srgba::new(rgb.x, rgb.y, rgb.z, srgba.alpha).into()
+}
+
+/// Text color before the [`ColorMode`] is applied, the [`TextColor`] follows it.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct BaseTextColor(pub Color);
+
+fn apply_text_color_mode(
+    color_mode: Res<ColorMode>,
+    mut texts: Query<(Ref<BaseTextColor>, &mut TextColor)>,
+) {
+    for (base, mut text_color) in &mut texts {
+        if color_mode.is_changed() || base.is_changed() {
+            text_color.0 = color_mode.adjust(base.0);
+        }
+    }
+}
+
+/// Widgets are spawned with their [`InteractionPalette`] colors as they are,
+/// the interaction observers take care of the mode after that.
+fn apply_palette_color_mode(
+    color_mode: Res<ColorMode>,
+    mut palettes: Query<(
+        Ref<InteractionPalette>,
+        Option<&mut BackgroundColor>,
+        Option<&mut ImageNode>,
+    )>,
+) {
+    for (palette, background, image) in &mut palettes {
+        if !color_mode.is_changed() && !palette.is_added() {
+            continue;
+        }
+
+        let color = color_mode.adjust(palette.none);
+        if let Some(mut image) = image {
+            image.color = color;
+        } else if let Some(mut background) = background {
+            background.0 = color;
+        }
+    }
+}
@@ -0,0 +1,352 @@
+//! Widgets that edit a value stored somewhere else, usually a resource field.
+//!
+//! Each widget holds a [`Binding`] to its value. The widget follows the value when it changes
+//! elsewhere, and user input writes it back and triggers a [`ValueChanged`] on the widget.
+//! Use the bundles in [`super::widget`] to spawn them.
+
+use std::sync::Arc;
+
+use bevy::{ecs::component::Mutable, prelude::*, ui::RelativeCursorPosition};
+
+use crate::{
+    localization::{Locale, Localized},
+    theme::focus::Adjust,
+};
+
+pub(super) fn plugin(app: &mut App) {
+    app.add_systems(
+        PreUpdate,
+        (
+            sync_from_binding::<Slider>,
+            sync_from_binding::<Toggle>,
+            sync_from_binding::<Selector>,
+        ),
+    );
+    app.add_systems(
+        Update,
+        (
+            update_slider_display,
+            update_toggle_display,
+            update_selector_display,
+        ),
+    );
+
+    app.add_observer(write_binding::<f32>);
+    app.add_observer(write_binding::<bool>);
+    app.add_observer(write_binding::<usize>);
+
+    app.add_observer(drag_slider_on_press);
+    app.add_observer(drag_slider_on_drag);
+    app.add_observer(adjust_slider);
+    app.add_observer(flip_toggle_on_click);
+    app.add_observer(cycle_selector_on_click);
+    app.add_observer(adjust_selector);
+}
+
+/// Reads and writes the value a widget edits.
+#[derive(Component, Clone)]
+pub struct Binding<T> {
+    read: Arc<dyn Fn(&World) -> T + Send + Sync>,
+    write: Arc<dyn Fn(&mut World, T) + Send + Sync>,
+}
+
+impl<T: 'static> Binding<T> {
+    /// Bind to a field of the resource `R`.
+    pub fn resource<R: Resource>(get: fn(&R) -> T, set: fn(&mut R, T)) -> Self {
+        Self {
+            read: Arc::new(move |world| get(world.resource::<R>())),
+            write: Arc::new(move |world, value| set(&mut world.resource_mut::<R>(), value)),
+        }
+    }
+}
+
+/// Triggered on a widget when the user changed its value.
+#[derive(EntityEvent, Debug, Clone)]
+pub struct ValueChanged<T: Clone + Send + Sync + 'static> {
+    pub entity: Entity,
+    pub value: T,
+}
+
+/// A widget component holding the value it displays.
+trait BoundWidget: Component<Mutability = Mutable> {
+    type Value: Clone + PartialEq + Send + Sync + 'static;
+
+    fn value(&self) -> Self::Value;
+    fn set_value(&mut self, value: Self::Value);
+}
+
+/// A horizontal bar that can be dragged, see [`super::widget::slider`].
+#[derive(Component, Debug, Clone)]
+pub struct Slider {
+    pub value: f32,
+    pub min: f32,
+    pub max: f32,
+    pub step: f32,
+    /// Formats the value for the label on the bar
+    pub format: fn(f32) -> String,
+}
+
+impl Slider {
+    fn ratio(&self) -> f32 {
+        if self.max > self.min {
+            ((self.value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// Snap `value` to a step within range. Without a step it is only kept in range.
+    fn snap(&self, value: f32) -> f32 {
+        if self.step <= 0.0 {
+            return value.clamp(self.min, self.max);
+        }
+        let steps = ((value - self.min) / self.step).round();
+        (self.min + steps * self.step).clamp(self.min, self.max)
+    }
+}
+
+impl BoundWidget for Slider {
+    type Value = f32;
+
+    fn value(&self) -> f32 {
+        self.value
+    }
+
+    fn set_value(&mut self, value: f32) {
+        self.value = value;
+    }
+}
+
+/// An on / off button, see [`super::widget::toggle`].
+#[derive(Component, Debug, Clone, Copy, Default)]
+pub struct Toggle(pub bool);
+
+impl BoundWidget for Toggle {
+    type Value = bool;
+
+    fn value(&self) -> bool {
+        self.0
+    }
+
+    fn set_value(&mut self, value: bool) {
+        self.0 = value;
+    }
+}
+
+/// A button that cycles through options, see [`super::widget::selector`].
+#[derive(Component, Debug, Clone, Default)]
+pub struct Selector {
+    pub options: Vec<Localized>,
+    pub index: usize,
+}
+
+impl Selector {
+    fn stepped(&self, step: i32) -> usize {
+        let len = self.options.len().max(1) as i32;
+        (self.index as i32 + step).rem_euclid(len) as usize
+    }
+}
+
+impl BoundWidget for Selector {
+    type Value = usize;
+
+    fn value(&self) -> usize {
+        self.index
+    }
+
+    fn set_value(&mut self, value: usize) {
+        self.index = value;
+    }
+}
+
+/// The filled part of a [`Slider`].
+#[derive(Component, Debug, Clone, Copy)]
+pub struct SliderFill;
+
+/// Text showing the value of a widget.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ValueLabel;
+
+fn sync_from_binding<W: BoundWidget>(world: &mut World) {
+    let mut query = world.query::<(Entity, &W, &Binding<W::Value>)>();
+    let outdated = query
+        .iter(world)
+        .filter_map(|(entity, widget, binding)| {
+            let value = (binding.read)(world);
+            (widget.value() != value).then_some((entity, value))
+        })
+        .collect::<Vec<_>>();
+
+    for (entity, value) in outdated {
+        if let Some(mut widget) = world.get_mut::<W>(entity) {
+            widget.set_value(value);
+        }
+    }
+}
+
+fn write_binding<T: Clone + Send + Sync + 'static>(
+    changed: On<ValueChanged<T>>,
+    bindings: Query<&Binding<T>>,
+    mut commands: Commands,
+) {
+    let Ok(binding) = bindings.get(changed.entity) else {
+        return;
+    };
+
+    let write = binding.write.clone();
+    let value = changed.value.clone();
+    commands.queue(move |world: &mut World| write(world, value));
+}
+
+/// Set the value of a widget from user input.
+fn change<W: BoundWidget>(
+    commands: &mut Commands,
+    entity: Entity,
+    widget: &mut W,
+    value: W::Value,
+) {
+    if widget.value() == value {
+        return;
+    }
+
+    widget.set_value(value.clone());
+    commands.trigger(ValueChanged { entity, value });
+}
+
+fn drag_slider_on_press(
+    press: On<Pointer<Press>>,
+    mut commands: Commands,
+    mut sliders: Query<(&mut Slider, &RelativeCursorPosition)>,
+) {
+    drag_slider(press.event_target(), &mut commands, &mut sliders);
+}
+
+fn drag_slider_on_drag(
+    drag: On<Pointer<Drag>>,
+    mut commands: Commands,
+    mut sliders: Query<(&mut Slider, &RelativeCursorPosition)>,
+) {
+    drag_slider(drag.event_target(), &mut commands, &mut sliders);
+}
+
+fn drag_slider(
+    entity: Entity,
+    commands: &mut Commands,
+    sliders: &mut Query<(&mut Slider, &RelativeCursorPosition)>,
+) {
+    let Ok((mut slider, cursor)) = sliders.get_mut(entity) else {
+        return;
+    };
+    let Some(normalized) = cursor.normalized else {
+        return;
+    };
+
+    // Normalized goes from -0.5 on the left edge to 0.5 on the right edge
+    let ratio = (normalized.x + 0.5).clamp(0.0, 1.0);
+    let value = slider.snap(slider.min + ratio * (slider.max - slider.min));
+    change(commands, entity, &mut *slider, value);
+}
+
+fn adjust_slider(adjust: On<Adjust>, mut commands: Commands, mut sliders: Query<&mut Slider>) {
+    let Ok(mut slider) = sliders.get_mut(adjust.entity) else {
+        return;
+    };
+
+    let value = slider.snap(slider.value + adjust.step as f32 * slider.step);
+    change(&mut commands, adjust.entity, &mut *slider, value);
+}
+
+fn flip_toggle_on_click(
+    click: On<Pointer<Click>>,
+    mut commands: Commands,
+    mut toggles: Query<&mut Toggle>,
+) {
+    let Ok(mut toggle) = toggles.get_mut(click.event_target()) else {
+        return;
+    };
+
+    let value = !toggle.0;
+    change(&mut commands, click.event_target(), &mut *toggle, value);
+}
+
+fn cycle_selector_on_click(
+    click: On<Pointer<Click>>,
+    mut commands: Commands,
+    mut selectors: Query<&mut Selector>,
+) {
+    let Ok(mut selector) = selectors.get_mut(click.event_target()) else {
+        return;
+    };
+
+    let value = selector.stepped(1);
+    change(&mut commands, click.event_target(), &mut *selector, value);
+}
+
+fn adjust_selector(
+    adjust: On<Adjust>,
+    mut commands: Commands,
+    mut selectors: Query<&mut Selector>,
+) {
+    let Ok(mut selector) = selectors.get_mut(adjust.entity) else {
+        return;
+    };
+
+    let value = selector.stepped(adjust.step);
+    change(&mut commands, adjust.entity, &mut *selector, value);
+}
+
+fn update_slider_display(
+    sliders: Query<(&Slider, &Children), Changed<Slider>>,
+    mut fills: Query<&mut Node, With<SliderFill>>,
+    mut labels: Query<&mut Text, With<ValueLabel>>,
+) {
+    for (slider, children) in &sliders {
+        for child in children {
+            if let Ok(mut fill) = fills.get_mut(*child) {
+                fill.width = percent(slider.ratio() * 100.0);
+            }
+            if let Ok(mut label) = labels.get_mut(*child) {
+                label.0 = (slider.format)(slider.value);
+            }
+        }
+    }
+}
+
+fn update_toggle_display(
+    locale: Locale,
+    toggles: Query<(Ref<Toggle>, &Children)>,
+    mut labels: Query<&mut Text, With<ValueLabel>>,
+) {
+    for (toggle, children) in &toggles {
+        if !toggle.is_changed() && !locale.is_changed() {
+            continue;
+        }
+        for child in children {
+            if let Ok(mut label) = labels.get_mut(*child) {
+                let key = if toggle.0 { "widget.on" } else { "widget.off" };
+                label.0 = locale.get(key, &[]);
+            }
+        }
+    }
+}
+
+fn update_selector_display(
+    locale: Locale,
+    selectors: Query<(Ref<Selector>, &Children)>,
+    mut labels: Query<&mut Text, With<ValueLabel>>,
+) {
+    for (selector, children) in &selectors {
+        if !selector.is_changed() && !locale.is_changed() {
+            continue;
+        }
+        for child in children {
+            if let Ok(mut label) = labels.get_mut(*child) {
+                label.0 = selector
+                    .options
+                    .get(selector.index)
+                    .map(|option| locale.text(option))
+                    .unwrap_or_default();
+            }
+        }
+    }
+}
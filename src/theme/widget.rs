@@ -5,11 +5,18 @@ use std::borrow::Cow;
 use bevy::{
     ecs::{spawn::SpawnWith, system::IntoObserverSystem},
     prelude::*,
+    ui::RelativeCursorPosition,
 };
 
 use crate::{
     float::Floats,
-    theme::{interaction::InteractionPalette, palette::*},
+    localization::Localized,
+    theme::{
+        focus::AdjustWithArrows,
+        interaction::InteractionPalette,
+        palette::*,
+        value_widget::{Binding, Selector, Slider, SliderFill, Toggle, ValueLabel},
+    },
 };
 
 /// A root UI node that fills the window and centers its content.
@@ -31,45 +38,57 @@ pub fn ui_root(name: impl Into<Cow<'static, str>>) -> impl Bundle {
 }
 
 /// A simple header label. Bigger than [`label`].
-pub fn header(text: impl Into<String>, font: Handle<Font>) -> impl Bundle {
+pub fn header(text: impl Into<Localized>, font: Handle<Font>) -> impl Bundle {
     (
         Name::new("Header"),
-        Text(text.into()),
+        Text::default(),
+        text.into(),
         TextFont::from(font.clone()).with_font_size(100.0),
         TextColor(HEADER_TEXT),
+        BaseTextColor(HEADER_TEXT),
         TextShadow::default(),
         Floats,
     )
 }
 
-pub fn header_with_color(text: impl Into<String>, color: Color, font: Handle<Font>) -> impl Bundle {
+pub fn header_with_color(
+    text: impl Into<Localized>,
+    color: Color,
+    font: Handle<Font>,
+) -> impl Bundle {
     (
         Name::new("Header"),
-        Text(text.into()),
+        Text::default(),
+        text.into(),
         TextFont::from(font.clone()).with_font_size(100.0),
         TextColor(color),
+        BaseTextColor(color),
         TextShadow::default(),
         Floats,
     )
 }
 
 /// A simple text label.
-pub fn label(text: impl Into<String>, font: Handle<Font>) -> impl Bundle {
+pub fn label(text: impl Into<Localized>, font: Handle<Font>) -> impl Bundle {
     (
         Name::new("Label"),
-        Text(text.into()),
+        Text::default(),
+        text.into(),
         TextFont::from(font.clone()).with_font_size(24.0),
         TextColor(LABEL_TEXT),
+        BaseTextColor(LABEL_TEXT),
         Floats,
     )
 }
 
-pub fn label_with_shadow(text: impl Into<String>, font: Handle<Font>) -> impl Bundle {
+pub fn label_with_shadow(text: impl Into<Localized>, font: Handle<Font>) -> impl Bundle {
     (
         Name::new("Label"),
-        Text(text.into()),
+        Text::default(),
+        text.into(),
         TextFont::from(font.clone()).with_font_size(35.0),
         TextColor(LABEL_TEXT),
+        BaseTextColor(LABEL_TEXT),
         TextShadow {
             offset: Vec2::splat(2.0),
             ..default()
@@ -79,7 +98,7 @@ pub fn label_with_shadow(text: impl Into<String>, font: Handle<Font>) -> impl Bu
 }
 
 pub fn image_button<E, B, M, I>(
-    text: impl Into<String>,
+    text: impl Into<Localized>,
     action: I,
     image: Handle<Image>,
     font: Handle<Font>,
@@ -122,9 +141,11 @@ where
                     },
                     children![(
                         Name::new("Button Text"),
-                        Text(text),
+                        Text::default(),
+                        text,
                         TextFont::from(font.clone()).with_font_size(24.0),
                         TextColor(BUTTON_TEXT),
+                        BaseTextColor(BUTTON_TEXT),
                         // Don't bubble picking events from the text up to the button.
                         Pickable::IGNORE,
                     )],
@@ -135,7 +156,7 @@ where
 }
 
 /// A large rounded button with text and an action defined as an [`Observer`].
-pub fn button<E, B, M, I>(text: impl Into<String>, action: I, font: Handle<Font>) -> impl Bundle
+pub fn button<E, B, M, I>(text: impl Into<Localized>, action: I, font: Handle<Font>) -> impl Bundle
 where
     E: EntityEvent,
     B: Bundle,
@@ -158,7 +179,7 @@ where
 
 /// A small square button with text and an action defined as an [`Observer`].
 pub fn button_small<E, B, M, I>(
-    text: impl Into<String>,
+    text: impl Into<Localized>,
     action: I,
     font: Handle<Font>,
 ) -> impl Bundle
@@ -183,7 +204,7 @@ where
 
 /// A simple button with text and an action defined as an [`Observer`]. The button's layout is provided by `button_bundle`.
 fn button_base<E, B, M, I>(
-    text: impl Into<String>,
+    text: impl Into<Localized>,
     action: I,
     button_bundle: impl Bundle,
     font: Handle<Font>,
@@ -212,9 +233,11 @@ where
                     },
                     children![(
                         Name::new("Button Text"),
-                        Text(text),
+                        Text::default(),
+                        text,
                         TextFont::from(font.clone()).with_font_size(24.0),
                         TextColor(BUTTON_TEXT),
+                        BaseTextColor(BUTTON_TEXT),
                         // Don't bubble picking events from the text up to the button.
                         Pickable::IGNORE,
                     )],
@@ -224,3 +247,127 @@ where
         })),
     )
 }
+
+/// A horizontal bar that edits a number between `min` and `max` in steps of `step`.
+/// Drag it with the mouse, or use left and right while focused.
+pub fn slider(
+    binding: Binding<f32>,
+    min: f32,
+    max: f32,
+    step: f32,
+    format: fn(f32) -> String,
+    font: Handle<Font>,
+) -> impl Bundle {
+    (
+        Name::new("Slider"),
+        Node {
+            width: px(200),
+            height: px(30),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        Button,
+        BackgroundColor(BUTTON_BACKGROUND),
+        InteractionPalette {
+            none: BUTTON_BACKGROUND,
+            hovered: BUTTON_HOVERED_BACKGROUND,
+            pressed: BUTTON_PRESSED_BACKGROUND,
+        },
+        RelativeCursorPosition::default(),
+        AdjustWithArrows,
+        Slider {
+            value: min,
+            min,
+            max,
+            step,
+            format,
+        },
+        binding,
+        children![
+            (
+                Name::new("Slider Fill"),
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: px(0),
+                    top: px(0),
+                    width: percent(0),
+                    height: percent(100),
+                    ..default()
+                },
+                BackgroundColor(SLIDER_FILL),
+                SliderFill,
+                Pickable::IGNORE,
+            ),
+            value_label(font),
+        ],
+    )
+}
+
+/// A button switching a value on and off.
+pub fn toggle(binding: Binding<bool>, font: Handle<Font>) -> impl Bundle {
+    (
+        Name::new("Toggle"),
+        value_button_node(),
+        Button,
+        BackgroundColor(BUTTON_BACKGROUND),
+        InteractionPalette {
+            none: BUTTON_BACKGROUND,
+            hovered: BUTTON_HOVERED_BACKGROUND,
+            pressed: BUTTON_PRESSED_BACKGROUND,
+        },
+        Toggle::default(),
+        binding,
+        children![value_label(font)],
+    )
+}
+
+/// A button cycling through `options`, the bound value is the index of the selected one.
+/// Click to go to the next option, or use left and right while focused.
+pub fn selector(
+    binding: Binding<usize>,
+    options: impl IntoIterator<Item = impl Into<Localized>>,
+    font: Handle<Font>,
+) -> impl Bundle {
+    (
+        Name::new("Selector"),
+        value_button_node(),
+        Button,
+        BackgroundColor(BUTTON_BACKGROUND),
+        InteractionPalette {
+            none: BUTTON_BACKGROUND,
+            hovered: BUTTON_HOVERED_BACKGROUND,
+            pressed: BUTTON_PRESSED_BACKGROUND,
+        },
+        AdjustWithArrows,
+        Selector {
+            options: options.into_iter().map(Into::into).collect(),
+            index: 0,
+        },
+        binding,
+        children![value_label(font)],
+    )
+}
+
+fn value_button_node() -> Node {
+    Node {
+        width: px(200),
+        height: px(30),
+        align_items: AlignItems::Center,
+        justify_content: JustifyContent::Center,
+        ..default()
+    }
+}
+
+fn value_label(font: Handle<Font>) -> impl Bundle {
+    (
+        Name::new("Value Label"),
+        Text::default(),
+        TextFont::from(font).with_font_size(24.0),
+        TextColor(BUTTON_TEXT),
+        BaseTextColor(BUTTON_TEXT),
+        ValueLabel,
+        // Don't bubble picking events from the text up to the widget.
+        Pickable::IGNORE,
+    )
+}
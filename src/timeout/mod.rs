@@ -2,12 +2,35 @@ use std::time::Duration;
 
 use bevy::{color::palettes::css, prelude::*};
 
-use crate::{AppSystems, PausableSystems, controls::progress_bar::ProgressBar};
+use crate::{
+    AppSystems, PausableSystems,
+    accessibility::ReducedMotion,
+    audio::sound_effect,
+    controls::progress_bar::{Fill, Pattern, ProgressBar},
+    easing::Easing,
+    theme::palette::ColorMode,
+    tween::{PausableTween, Tween, UiScaleLens},
+};
+
+/// [`TimeoutLabel`] ticks for this many seconds before timing out
+const TICK_SECONDS: u32 = 3;
+const PULSE_SCALE: f32 = 1.3;
+const PULSE_DURATION: Duration = Duration::from_millis(250);
+/// Thickness of horizontal and vertical [`TimeoutBar`]s
+const BAR_THICKNESS: f32 = 10.0;
+/// Size of radial and clock wipe [`TimeoutBar`]s
+const RADIAL_BAR_SIZE: f32 = 80.0;
+
+/// Thresholds for [`TimeoutBar::with_thresholds`] turning the bar orange then red
+pub const WARNING_THRESHOLDS: &[(f32, Color)] = &[
+    (0.5, Color::srgb(1.0, 0.65, 0.0)),
+    (0.2, Color::srgb(1.0, 0.0, 0.0)),
+];
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(
         Update,
-        (spawn, update, spawn_bar, update_bar)
+        (spawn, update, spawn_bar, update_bar, update_label)
             .in_set(AppSystems::Update)
             .in_set(PausableSystems),
     );
@@ -39,8 +62,14 @@ impl Default for Timeout {
 
 #[derive(Debug, Clone, Copy, Component)]
 pub struct TimeoutBar {
+    /// Adjusted for the [`ColorMode`] when shown
     pub foreground_color: Color,
     pub background_color: Color,
+    pub pattern: Pattern,
+    pub fill: Fill,
+    /// Colors the bar blends towards as it drains, as `(progress left, color)` from
+    /// most to least progress left. Adjusted for the [`ColorMode`] too.
+    pub thresholds: &'static [(f32, Color)],
 }
 
 impl TimeoutBar {
@@ -50,6 +79,34 @@ impl TimeoutBar {
             ..default()
         }
     }
+
+    pub fn with_pattern(mut self, pattern: Pattern) -> Self {
+        self.pattern = pattern;
+        self
+    }
+
+    pub fn with_fill(mut self, fill: Fill) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    pub fn with_thresholds(mut self, thresholds: &'static [(f32, Color)]) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    /// The color of the bar with `progress` left
+    fn color(&self, progress: f32) -> Color {
+        let mut from = (1.0, self.foreground_color);
+        for &to in self.thresholds {
+            if progress >= to.0 {
+                let ratio = (from.0 - progress) / (from.0 - to.0).max(f32::EPSILON);
+                return from.1.mix(&to.1, ratio.clamp(0.0, 1.0));
+            }
+            from = to;
+        }
+        from.1
+    }
 }
 
 impl Default for TimeoutBar {
@@ -57,11 +114,36 @@ impl Default for TimeoutBar {
         Self {
             foreground_color: css::BLUE_VIOLET.into(),
             background_color: Color::srgba(0.0, 0.0, 0.0, 0.0),
+            pattern: Pattern::Solid,
+            fill: Fill::Horizontal,
+            thresholds: &[],
+        }
+    }
+}
+
+/// Shows the whole seconds left on a [`Timeout`] and pulses when the number changes
+#[derive(Debug, Clone, Default, Component)]
+#[require(Text)]
+pub struct TimeoutLabel {
+    /// Played every second for the last few seconds
+    pub tick: Option<Handle<AudioSource>>,
+    /// Seconds currently shown
+    shown: Option<u32>,
+}
+
+impl TimeoutLabel {
+    pub fn with_tick(tick: Handle<AudioSource>) -> Self {
+        Self {
+            tick: Some(tick),
+            shown: None,
         }
     }
 }
 
-// TODO: ADD LABEL FOR COUNTDOWN
+/// The [`Timeout`] a [`TimeoutBar`] or [`TimeoutLabel`] shows.
+/// Without it they show the closest [`Timeout`] up their hierarchy.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct TimeoutOf(pub Entity);
 
 #[derive(Debug, Default, Clone, Copy, Component)]
 pub struct TimeoutState {
@@ -78,6 +160,25 @@ impl TimeoutState {
             timed_out: false,
         }
     }
+
+    fn remaining(&self, now: Duration) -> Duration {
+        self.run_time.saturating_sub(now - self.start_time)
+    }
+}
+
+/// Find the state of the [`Timeout`] shown by `entity`, see [`TimeoutOf`].
+fn find_state<'a>(
+    entity: Entity,
+    timeout_of: Option<&TimeoutOf>,
+    parents: &Query<&ChildOf>,
+    states: &'a Query<&TimeoutState>,
+) -> Option<&'a TimeoutState> {
+    match timeout_of {
+        Some(timeout_of) => states.get(timeout_of.0).ok(),
+        None => parents
+            .iter_ancestors(entity)
+            .find_map(|ancestor| states.get(ancestor).ok()),
+    }
 }
 
 fn spawn(
@@ -107,42 +208,114 @@ fn update(
 
 fn spawn_bar(mut commands: Commands, query: Query<(Entity, &TimeoutBar), Added<TimeoutBar>>) {
     for (entity, timeout_bar) in query.iter() {
-        commands.entity(entity).insert((
-            Node {
+        let node = match timeout_bar.fill {
+            Fill::Horizontal => Node {
                 bottom: px(0),
                 left: px(0),
                 right: px(0),
+                height: px(BAR_THICKNESS),
                 margin: UiRect::horizontal(Val::Auto),
-                padding: UiRect::all(Val::Px(5.0)),
+                ..default()
+            },
+            Fill::Vertical => Node {
+                top: px(0),
+                bottom: px(0),
+                right: px(0),
+                width: px(BAR_THICKNESS),
+                margin: UiRect::vertical(Val::Auto),
+                ..default()
+            },
+            Fill::Radial | Fill::ClockWipe { .. } => Node {
+                bottom: px(BAR_THICKNESS),
+                right: px(BAR_THICKNESS),
+                width: px(RADIAL_BAR_SIZE),
+                height: px(RADIAL_BAR_SIZE),
+                border_radius: BorderRadius::MAX,
+                ..default()
+            },
+        };
+        commands.entity(entity).insert((
+            Node {
                 display: Display::Block,
                 position_type: PositionType::Absolute,
-                ..default()
+                ..node
             },
             BackgroundColor(timeout_bar.background_color),
             ProgressBar {
                 color: timeout_bar.foreground_color,
-                vertical: false,
+                fill: timeout_bar.fill,
+                pattern: timeout_bar.pattern,
+                progress: 1.0,
                 ..default()
             },
         ));
     }
 }
 
-// TODO: Support hierarchy where `Timeout` and label are not direct child/parent
 fn update_bar(
-    mut label_query: Query<(&ChildOf, &mut ProgressBar), With<TimeoutBar>>,
+    mut bar_query: Query<(Entity, &TimeoutBar, Option<&TimeoutOf>, &mut ProgressBar)>,
+    parents: Query<&ChildOf>,
     timeout_query: Query<&TimeoutState>,
     time: Res<Time>,
+    color_mode: Res<ColorMode>,
 ) {
-    for (parent, mut bar) in label_query.iter_mut() {
-        if let Ok(state) = timeout_query.get(parent.0) {
-            let elapsed = time.elapsed() - state.start_time;
-            let countdown = if elapsed < state.run_time {
-                1.0 - (elapsed.as_secs_f32().floor() / state.run_time.as_secs_f32())
+    for (entity, timeout_bar, timeout_of, mut bar) in bar_query.iter_mut() {
+        if let Some(state) = find_state(entity, timeout_of, &parents, &timeout_query) {
+            bar.progress = if state.run_time.is_zero() {
+                0.0
             } else {
-                1.0
+                state.remaining(time.elapsed()).as_secs_f32() / state.run_time.as_secs_f32()
             };
-            bar.progress = countdown;
+        }
+        bar.color = color_mode.adjust(timeout_bar.color(bar.progress));
+    }
+}
+
+fn update_label(
+    mut commands: Commands,
+    mut label_query: Query<(Entity, &mut TimeoutLabel, Option<&TimeoutOf>, &mut Text)>,
+    parents: Query<&ChildOf>,
+    timeout_query: Query<&TimeoutState>,
+    time: Res<Time>,
+    reduced_motion: Res<ReducedMotion>,
+) {
+    for (entity, mut label, timeout_of, mut text) in label_query.iter_mut() {
+        let Some(state) = find_state(entity, timeout_of, &parents, &timeout_query) else {
+            continue;
+        };
+
+        let seconds = state.remaining(time.elapsed()).as_secs_f32().ceil() as u32;
+        // Compared to the text as well, as spawning the label may have reset it
+        let shown = seconds.to_string();
+        if text.0 != shown {
+            text.0 = shown;
+        }
+        if label.shown == Some(seconds) {
+            continue;
+        }
+        let first = label.shown.is_none();
+        label.shown = Some(seconds);
+        if first {
+            continue;
+        }
+
+        if !reduced_motion.0 {
+            commands.entity(entity).insert((
+                PausableTween,
+                Tween::new(
+                    UiScaleLens {
+                        start: Vec2::splat(PULSE_SCALE),
+                        end: Vec2::ONE,
+                    },
+                    PULSE_DURATION,
+                    Easing::QuadOut,
+                ),
+            ));
+        }
+        if let Some(tick) = &label.tick
+            && (1..=TICK_SECONDS).contains(&seconds)
+        {
+            commands.spawn(sound_effect(tick.clone()));
         }
     }
 }
@@ -2,7 +2,15 @@ use std::time::Duration;
 
 use bevy::prelude::*;
 
-use crate::{AppSystems, PausableSystems};
+use crate::{
+    AppSystems, PausableSystems,
+    accessibility::ReducedMotion,
+    easing::Easing,
+    tween::{ImageNodeColorLens, Tween},
+};
+
+/// How long the fade takes with [`ReducedMotion`] on, half to black and half back
+const FADE_DURATION: Duration = Duration::from_millis(400);
 
 pub(super) fn plugin(app: &mut App) {
     app.add_systems(
@@ -17,11 +25,55 @@ pub struct TimedImageChange {
     pub next: Handle<Image>,
 }
 
-fn update(mut query: Query<(&TimedImageChange, &mut ImageNode)>, time: Res<Time>) {
-    for (transition, mut image) in query.iter_mut() {
-        if time.elapsed() > transition.transition_time && image.image != transition.next {
-            image.image = transition.next.clone();
-            info!("Background changed");
+/// The image is fading out and changes once it is black
+#[derive(Debug, Component)]
+struct Fading {
+    change_time: Duration,
+}
+
+fn update(
+    mut commands: Commands,
+    mut query: Query<(Entity, &TimedImageChange, &mut ImageNode, Option<&Fading>)>,
+    time: Res<Time>,
+    reduced_motion: Res<ReducedMotion>,
+) {
+    for (entity, transition, mut image, fading) in query.iter_mut() {
+        if time.elapsed() <= transition.transition_time || image.image == transition.next {
+            continue;
         }
+
+        if reduced_motion.0 && fading.is_none() {
+            let half = FADE_DURATION / 2;
+            commands.entity(entity).insert((
+                Fading {
+                    change_time: time.elapsed() + half,
+                },
+                Tween::new(
+                    ImageNodeColorLens {
+                        start: image.color,
+                        end: Color::BLACK,
+                    },
+                    half,
+                    Easing::SineInOut,
+                )
+                .then(
+                    ImageNodeColorLens {
+                        start: Color::BLACK,
+                        end: image.color,
+                    },
+                    half,
+                    Easing::SineInOut,
+                ),
+            ));
+            continue;
+        }
+        if fading.is_some_and(|fading| time.elapsed() < fading.change_time) {
+            continue;
+        }
+
+        image.image = transition.next.clone();
+        // The tween carries on fading back in by itself
+        commands.entity(entity).remove::<Fading>();
+        info!("Background changed");
     }
 }
@@ -0,0 +1,163 @@
+use bevy::{audio::Volume, ecs::component::Mutable, prelude::*};
+
+/// Describes how a [`super::Tween`] writes an interpolated value into a component.
+pub trait TweenLens: Send + Sync + 'static {
+    /// The component this lens animates.
+    type Target: Component<Mutability = Mutable>;
+
+    /// Write the value at `ratio` (the already eased 0.0 -> 1.0 progress) into `target`.
+    fn apply(&self, target: &mut Self::Target, ratio: f32);
+}
+
+/// Animates [`Transform::translation`].
+#[derive(Debug, Clone, Copy)]
+pub struct TranslationLens {
+    pub start: Vec3,
+    pub end: Vec3,
+}
+
+impl TweenLens for TranslationLens {
+    type Target = Transform;
+
+    fn apply(&self, target: &mut Transform, ratio: f32) {
+        target.translation = self.start.lerp(self.end, ratio);
+    }
+}
+
+/// Animates [`Transform::rotation`].
+#[derive(Debug, Clone, Copy)]
+pub struct RotationLens {
+    pub start: Quat,
+    pub end: Quat,
+}
+
+impl TweenLens for RotationLens {
+    type Target = Transform;
+
+    fn apply(&self, target: &mut Transform, ratio: f32) {
+        target.rotation = self.start.slerp(self.end, ratio);
+    }
+}
+
+/// Animates [`Transform::scale`].
+#[derive(Debug, Clone, Copy)]
+pub struct ScaleLens {
+    pub start: Vec3,
+    pub end: Vec3,
+}
+
+impl TweenLens for ScaleLens {
+    type Target = Transform;
+
+    fn apply(&self, target: &mut Transform, ratio: f32) {
+        target.scale = self.start.lerp(self.end, ratio);
+    }
+}
+
+/// Animates [`Sprite::color`].
+#[derive(Debug, Clone, Copy)]
+pub struct SpriteColorLens {
+    pub start: Color,
+    pub end: Color,
+}
+
+impl TweenLens for SpriteColorLens {
+    type Target = Sprite;
+
+    fn apply(&self, target: &mut Sprite, ratio: f32) {
+        target.color = self.start.mix(&self.end, ratio);
+    }
+}
+
+/// Animates [`ImageNode::color`].
+#[derive(Debug, Clone, Copy)]
+pub struct ImageNodeColorLens {
+    pub start: Color,
+    pub end: Color,
+}
+
+impl TweenLens for ImageNodeColorLens {
+    type Target = ImageNode;
+
+    fn apply(&self, target: &mut ImageNode, ratio: f32) {
+        target.color = self.start.mix(&self.end, ratio);
+    }
+}
+
+/// Animates the `left` and `top` of a [`Node`] in pixels.
+#[derive(Debug, Clone, Copy)]
+pub struct NodePositionLens {
+    pub start: Vec2,
+    pub end: Vec2,
+}
+
+impl TweenLens for NodePositionLens {
+    type Target = Node;
+
+    fn apply(&self, target: &mut Node, ratio: f32) {
+        let pos = self.start.lerp(self.end, ratio);
+        target.left = px(pos.x);
+        target.top = px(pos.y);
+    }
+}
+
+/// Animates [`UiTransform::translation`] in pixels.
+#[derive(Debug, Clone, Copy)]
+pub struct UiTranslationLens {
+    pub start: Vec2,
+    pub end: Vec2,
+}
+
+impl TweenLens for UiTranslationLens {
+    type Target = UiTransform;
+
+    fn apply(&self, target: &mut UiTransform, ratio: f32) {
+        let pos = self.start.lerp(self.end, ratio);
+        target.translation = Val2::px(pos.x, pos.y);
+    }
+}
+
+/// Animates [`UiTransform::rotation`].
+#[derive(Debug, Clone, Copy)]
+pub struct UiRotationLens {
+    pub start: Rot2,
+    pub end: Rot2,
+}
+
+impl TweenLens for UiRotationLens {
+    type Target = UiTransform;
+
+    fn apply(&self, target: &mut UiTransform, ratio: f32) {
+        target.rotation = self.start.slerp(self.end, ratio);
+    }
+}
+
+/// Animates [`UiTransform::scale`].
+#[derive(Debug, Clone, Copy)]
+pub struct UiScaleLens {
+    pub start: Vec2,
+    pub end: Vec2,
+}
+
+impl TweenLens for UiScaleLens {
+    type Target = UiTransform;
+
+    fn apply(&self, target: &mut UiTransform, ratio: f32) {
+        target.scale = self.start.lerp(self.end, ratio);
+    }
+}
+
+/// Animates the linear volume of a playing [`AudioSink`].
+#[derive(Debug, Clone, Copy)]
+pub struct VolumeLens {
+    pub start: f32,
+    pub end: f32,
+}
+
+impl TweenLens for VolumeLens {
+    type Target = AudioSink;
+
+    fn apply(&self, target: &mut AudioSink, ratio: f32) {
+        target.set_volume(Volume::Linear(self.start.lerp(self.end, ratio)));
+    }
+}
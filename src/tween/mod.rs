@@ -0,0 +1,206 @@
+//! Generic tweening of component values.
+//!
+//! Add a [`Tween`] with a [`TweenLens`] to an entity and the lens target is animated
+//! over time. A tween can be made of several steps played as a sequence, can be
+//! delayed, repeated and played back and forth. A [`TweenCompleted`] event is triggered
+//! on the entity when the last repeat finishes.
+//!
+//! Tweens keep playing while the game is paused, so menus stay animated. Gameplay
+//! entities add [`PausableTween`] to stop their tweens with the rest of the game.
+
+use std::time::Duration;
+
+use bevy::{ecs::query::QueryFilter, prelude::*};
+
+use crate::{AppSystems, PausableSystems, easing::Easing};
+
+mod lens;
+
+pub use lens::*;
+
+pub(super) fn plugin(app: &mut App) {
+    add_lens::<TranslationLens>(app);
+    add_lens::<RotationLens>(app);
+    add_lens::<ScaleLens>(app);
+    add_lens::<SpriteColorLens>(app);
+    add_lens::<ImageNodeColorLens>(app);
+    add_lens::<NodePositionLens>(app);
+    add_lens::<UiTranslationLens>(app);
+    add_lens::<UiRotationLens>(app);
+    add_lens::<UiScaleLens>(app);
+    add_lens::<VolumeLens>(app);
+}
+
+/// Animate tweens of `L`, stopping those on [`PausableTween`] entities while paused
+fn add_lens<L: TweenLens>(app: &mut App) {
+    app.add_systems(
+        Update,
+        (
+            animate::<L, Without<PausableTween>>,
+            animate::<L, With<PausableTween>>.in_set(PausableSystems),
+        )
+            .in_set(AppSystems::Update),
+    );
+}
+
+/// Tweens on this entity stop while the game is paused
+#[derive(Debug, Default, Clone, Copy, Component)]
+pub struct PausableTween;
+
+#[derive(EntityEvent)]
+pub struct TweenCompleted {
+    pub entity: Entity,
+}
+
+/// How many times a [`Tween`] plays its steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TweenRepeat {
+    Times(u32),
+    Forever,
+}
+
+/// A single segment of a [`Tween`].
+#[derive(Debug, Clone)]
+pub struct TweenStep<L: TweenLens> {
+    pub lens: L,
+    pub duration: Duration,
+    pub easing: Easing,
+}
+
+#[derive(Debug, Clone, Component)]
+pub struct Tween<L: TweenLens> {
+    steps: Vec<TweenStep<L>>,
+    delay: Duration,
+    repeat: TweenRepeat,
+    yoyo: bool,
+    elapsed: Duration,
+    completed: bool,
+}
+
+impl<L: TweenLens> Tween<L> {
+    pub fn new(lens: L, duration: Duration, easing: Easing) -> Self {
+        Self {
+            steps: vec![TweenStep {
+                lens,
+                duration,
+                easing,
+            }],
+            delay: Duration::ZERO,
+            repeat: TweenRepeat::Times(1),
+            yoyo: false,
+            elapsed: Duration::ZERO,
+            completed: false,
+        }
+    }
+
+    /// Append a step that plays after all previous steps.
+    pub fn then(mut self, lens: L, duration: Duration, easing: Easing) -> Self {
+        self.steps.push(TweenStep {
+            lens,
+            duration,
+            easing,
+        });
+        self
+    }
+
+    /// Wait before the first step starts, the target is left as is meanwhile.
+    /// Repeats follow each other without waiting again.
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    pub fn with_repeat(mut self, repeat: TweenRepeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Play every other repeat in reverse.
+    pub fn with_yoyo(mut self, yoyo: bool) -> Self {
+        self.yoyo = yoyo;
+        self
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.completed
+    }
+
+    fn cycle_duration(&self) -> Duration {
+        self.steps.iter().map(|step| step.duration).sum()
+    }
+
+    /// Advance the tween and return the step to apply with its eased ratio.
+    /// `None` means nothing should be written this frame.
+    fn tick(&mut self, delta: Duration) -> Option<(&TweenStep<L>, f32)> {
+        if self.steps.is_empty() {
+            self.completed = true;
+            return None;
+        }
+
+        self.elapsed += delta;
+        if self.elapsed < self.delay {
+            return None;
+        }
+
+        let active = (self.elapsed - self.delay).as_secs_f32();
+        let cycle = self.cycle_duration().as_secs_f32();
+        let cycles = if cycle > 0.0 {
+            (active / cycle).floor() as u32
+        } else {
+            u32::MAX
+        };
+
+        if let TweenRepeat::Times(times) = self.repeat
+            && cycles >= times
+        {
+            self.completed = true;
+            // Finish on the exact end state of the last cycle
+            return if self.yoyo && times % 2 == 0 {
+                self.steps.first().map(|step| (step, step.easing.ease(0.0)))
+            } else {
+                self.steps.last().map(|step| (step, step.easing.ease(1.0)))
+            };
+        }
+
+        let mut local = active - (cycles as f32 * cycle);
+        if self.yoyo && cycles % 2 == 1 {
+            local = cycle - local;
+        }
+
+        let mut step_start = 0.0;
+        for step in &self.steps {
+            let step_duration = step.duration.as_secs_f32();
+            if local <= step_start + step_duration {
+                let t = if step_duration > 0.0 {
+                    (local - step_start) / step_duration
+                } else {
+                    1.0
+                };
+                return Some((step, step.easing.ease(t)));
+            }
+            step_start += step_duration;
+        }
+
+        self.steps.last().map(|step| (step, step.easing.ease(1.0)))
+    }
+}
+
+fn animate<L: TweenLens, F: QueryFilter>(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Tween<L>, &mut L::Target), F>,
+    time: Res<Time>,
+) {
+    for (entity, mut tween, mut target) in query.iter_mut() {
+        if tween.is_completed() {
+            continue;
+        }
+
+        if let Some((step, ratio)) = tween.tick(time.delta()) {
+            step.lens.apply(&mut target, ratio);
+        }
+
+        if tween.is_completed() {
+            commands.trigger(TweenCompleted { entity });
+        }
+    }
+}